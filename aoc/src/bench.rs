@@ -0,0 +1,234 @@
+use crate::verify::format_duration;
+use common::{Answer, ParseError, Puzzle};
+use serde_json::json;
+use std::{fmt, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// Every measured run of one phase of a day.
+#[derive(Debug)]
+pub struct Sample {
+    pub day: u8,
+    pub phase: Phase,
+    pub runs: Vec<Duration>,
+}
+
+impl Sample {
+    pub fn min(&self) -> Duration {
+        self.runs.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.runs.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> Duration {
+        match self.runs.len() {
+            0 => Duration::ZERO,
+            n => self.runs.iter().sum::<Duration>() / n as u32,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut runs = self.runs.clone();
+        runs.sort();
+
+        match runs.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (runs[n / 2 - 1] + runs[n / 2]) / 2,
+            n => runs[n / 2],
+        }
+    }
+}
+
+/// Why a day couldn't be timed.
+#[derive(Debug, PartialEq)]
+pub enum BenchError {
+    Parse(ParseError),
+    /// A part gave an error instead of an answer.
+    Unsolved {
+        part: u8,
+        err: String,
+    },
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BenchError::Parse(err) => write!(f, "{err}"),
+            BenchError::Unsolved { part, err } => write!(f, "part {part} failed: {err}"),
+        }
+    }
+}
+
+/// Runs a day `repeat` times, parsing once per run. A part that gives an
+/// error stops it, since its time isn't a solve time.
+pub fn bench(puzzle: &dyn Puzzle, input: &str, repeat: usize) -> Result<Vec<Sample>, BenchError> {
+    let mut samples = vec![Sample {
+        day: puzzle.day(),
+        phase: Phase::Parse,
+        runs: Vec::new(),
+    }];
+    samples.extend((1..=puzzle.parts()).map(|part| Sample {
+        day: puzzle.day(),
+        phase: Phase::Part(part),
+        runs: Vec::new(),
+    }));
+
+    for _ in 0..repeat {
+        let timings = puzzle.timed(input).map_err(BenchError::Parse)?;
+
+        samples[0].runs.push(timings.parse);
+        for (sample, (answer, elapsed)) in samples[1..].iter_mut().zip(timings.parts) {
+            if let (Phase::Part(part), Answer::Error(err)) = (sample.phase, answer) {
+                return Err(BenchError::Unsolved { part, err });
+            }
+            sample.runs.push(elapsed);
+        }
+    }
+
+    Ok(samples)
+}
+
+impl fmt::Display for Sample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}  {:>9}",
+            format!("{:02}", self.day),
+            self.phase.to_string(),
+            format_duration(self.min()),
+            format_duration(self.median()),
+            format_duration(self.mean()),
+            format_duration(self.max())
+        )
+    }
+}
+
+pub fn to_json(samples: &[Sample]) -> String {
+    let samples = samples
+        .iter()
+        .map(|sample| {
+            json!({
+                "day": sample.day,
+                "phase": sample.phase.to_string(),
+                "runs": sample.runs.len(),
+                "min_ns": sample.min().as_nanos() as u64,
+                "median_ns": sample.median().as_nanos() as u64,
+                "mean_ns": sample.mean().as_nanos() as u64,
+                "max_ns": sample.max().as_nanos() as u64,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string_pretty(&samples).unwrap()
+}
+
+pub fn to_csv(samples: &[Sample]) -> String {
+    let mut csv = "day,phase,runs,min_ns,median_ns,mean_ns,max_ns\n".to_string();
+
+    for sample in samples {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            sample.day,
+            sample.phase,
+            sample.runs.len(),
+            sample.min().as_nanos(),
+            sample.median().as_nanos(),
+            sample.mean().as_nanos(),
+            sample.max().as_nanos()
+        ));
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(runs: &[u64]) -> Sample {
+        Sample {
+            day: 1,
+            phase: Phase::Parse,
+            runs: runs.iter().map(|&ms| Duration::from_millis(ms)).collect(),
+        }
+    }
+
+    #[test]
+    fn it_summarises_runs() {
+        let odd = sample(&[5, 1, 3]);
+        assert_eq!(odd.min(), Duration::from_millis(1));
+        assert_eq!(odd.median(), Duration::from_millis(3));
+        assert_eq!(odd.mean(), Duration::from_millis(3));
+        assert_eq!(odd.max(), Duration::from_millis(5));
+
+        let even = sample(&[4, 1, 2, 9]);
+        assert_eq!(even.median(), Duration::from_millis(3));
+        assert_eq!(even.mean(), Duration::from_millis(4));
+
+        assert_eq!(sample(&[]).median(), Duration::ZERO);
+    }
+
+    #[test]
+    fn it_benches_every_phase() {
+        let input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        let samples = bench(&day01::Day01, input, 3).unwrap();
+
+        let phases = samples.iter().map(|sample| sample.phase);
+        assert_eq!(
+            phases.collect::<Vec<_>>(),
+            vec![Phase::Parse, Phase::Part(1), Phase::Part(2)]
+        );
+        assert!(samples.iter().all(|sample| sample.runs.len() == 3));
+    }
+
+    #[test]
+    fn it_stops_on_errors() {
+        assert!(matches!(
+            bench(&day01::Day01, "3   4\n4", 1),
+            Err(BenchError::Parse(_))
+        ));
+        assert_eq!(
+            bench(&day17::Day17, "Program: 0,7", 1).unwrap_err(),
+            BenchError::Unsolved {
+                part: 1,
+                err: "combo operand 7 is reserved, found at 0".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn it_writes_csv_and_json() {
+        let samples = vec![sample(&[1, 3])];
+
+        assert_eq!(
+            to_csv(&samples),
+            "day,phase,runs,min_ns,median_ns,mean_ns,max_ns
+1,parse,2,1000000,2000000,2000000,3000000
+"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&samples)).unwrap();
+        assert_eq!(json[0]["phase"], "parse");
+        assert_eq!(json[0]["median_ns"], 2_000_000);
+    }
+}
@@ -0,0 +1,142 @@
+use grid::{Colour, Frame};
+use std::io::Write;
+
+/// The width and height in pixels of one cell.
+pub const SCALE: usize = 4;
+
+/// Every colour a frame can use, in the order of [`Colour::ALL`], so a
+/// colour's index is its position in the palette.
+fn palette() -> Vec<u8> {
+    Colour::ALL.iter().flat_map(|colour| colour.rgb()).collect()
+}
+
+fn size(frame: &Frame) -> (usize, usize) {
+    (frame.cells.width() * SCALE, frame.cells.height() * SCALE)
+}
+
+/// The frame as one palette index per pixel, row by row. Characters and the
+/// caption are not drawn, only each cell's colour.
+fn pixels(frame: &Frame) -> Vec<u8> {
+    let (width, height) = size(frame);
+    let mut pixels = vec![0; width * height];
+
+    for (point, cell) in frame.cells.iter() {
+        let (x, y) = (point.x as usize * SCALE, point.y as usize * SCALE);
+        for row in y..y + SCALE {
+            pixels[row * width + x..row * width + x + SCALE].fill(cell.colour as u8);
+        }
+    }
+
+    pixels
+}
+
+pub fn write_png(frame: &Frame, out: impl Write) -> Result<(), png::EncodingError> {
+    let (width, height) = size(frame);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette());
+
+    encoder.write_header()?.write_image_data(&pixels(frame))
+}
+
+/// Writes frames to an animated GIF as they are produced. The size of the
+/// image is taken from the first frame.
+pub struct GifWriter<W: Write> {
+    out: Option<W>,
+    encoder: Option<gif::Encoder<W>>,
+    /// How long each frame is shown, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    /// A writer that shows `fps` frames per second, or as many as the format
+    /// allows if `fps` is 0.
+    pub fn new(out: W, fps: u32) -> Self {
+        let delay = match fps {
+            0 => 2,
+            fps => (100 / fps).clamp(2, u16::MAX as u32) as u16,
+        };
+
+        GifWriter {
+            out: Some(out),
+            encoder: None,
+            delay,
+        }
+    }
+
+    pub fn write(&mut self, frame: &Frame) -> Result<(), gif::EncodingError> {
+        let (width, height) = size(frame);
+
+        if let Some(out) = self.out.take() {
+            let mut encoder = gif::Encoder::new(out, width as u16, height as u16, &palette())?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.encoder = Some(encoder);
+        }
+
+        let mut image =
+            gif::Frame::from_indexed_pixels(width as u16, height as u16, pixels(frame), None);
+        image.delay = self.delay;
+
+        self.encoder.as_mut().unwrap().write_frame(&image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::{Cell, Grid};
+
+    fn frame() -> Frame {
+        Grid::from_fn(2, 1, |p| p.x == 0).frame(|_, &robot| {
+            if robot {
+                Cell::new('#', Colour::Green)
+            } else {
+                Cell::new('.', Colour::Grey)
+            }
+        })
+    }
+
+    #[test]
+    fn it_scales_cells_to_pixels() {
+        let pixels = pixels(&frame());
+        let (green, grey) = (Colour::Green as u8, Colour::Grey as u8);
+
+        assert_eq!(pixels.len(), 2 * SCALE * SCALE);
+        assert_eq!(pixels[0], green);
+        assert_eq!(pixels[SCALE - 1], green);
+        assert_eq!(pixels[SCALE], grey);
+        assert_eq!(pixels[pixels.len() - 1], grey);
+        assert_eq!(palette()[green as usize * 3..][..3], Colour::Green.rgb());
+    }
+
+    #[test]
+    fn it_writes_a_png() {
+        let mut png = Vec::new();
+        write_png(&frame(), &mut png).unwrap();
+
+        let decoder = png::Decoder::new(png.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!(reader.info().width, 2 * SCALE as u32);
+        assert_eq!(reader.info().height, SCALE as u32);
+    }
+
+    #[test]
+    fn it_writes_an_animated_gif() {
+        let mut gif = Vec::new();
+        let mut writer = GifWriter::new(&mut gif, 10);
+        writer.write(&frame()).unwrap();
+        writer.write(&frame()).unwrap();
+        drop(writer);
+
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        assert_eq!(decoder.width() as usize, 2 * SCALE);
+
+        let mut n_frames = 0;
+        while let Some(image) = decoder.read_next_frame().unwrap() {
+            assert_eq!(image.delay, 10);
+            n_frames += 1;
+        }
+        assert_eq!(n_frames, 2);
+    }
+}
@@ -0,0 +1,314 @@
+mod bench;
+mod export;
+mod registry;
+mod verify;
+mod visualize;
+
+use common::{Answer, Puzzle};
+use export::GifWriter;
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    process,
+};
+use verify::{Answers, Status};
+use visualize::Player;
+
+const USAGE: &str = "Usage:
+    aoc run <day> <part> [input] [--visualize] [--fps <n>] [--skip <n>]
+                                 [--png <dir>] [--gif <file>]
+    aoc run all
+    aoc verify [answers.toml]
+    aoc bench [day] [--repeat <n>] [--json <file>] [--csv <file>]";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(1);
+}
+
+/// Finds the input file for a day. Older days keep their input next to the
+/// crate's `Cargo.toml`, newer days keep it in `src/bin`.
+fn default_input(day: u8) -> PathBuf {
+    let candidates = [
+        PathBuf::from(format!("day{day:02}/src/bin/input.txt")),
+        PathBuf::from(format!("day{day:02}/input.txt")),
+    ];
+
+    candidates
+        .iter()
+        .find(|path| path.exists())
+        .unwrap_or(&candidates[0])
+        .clone()
+}
+
+fn run_puzzle(puzzle: &dyn Puzzle, parts: &[u8], path: &PathBuf) -> Result<(), String> {
+    let input = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+    for &part in parts {
+        let answer = puzzle
+            .solve(part, &input)
+            .ok_or_else(|| format!("day {} has no part {part}", puzzle.day()))?
+            .map_err(|err| format!("{}:{err}", path.display()))?;
+        if let Answer::Error(err) = answer {
+            return Err(format!("day {:02} part {part} failed: {err}", puzzle.day()));
+        }
+
+        println!("Day {:02} part {part}: {answer}", puzzle.day());
+    }
+    Ok(())
+}
+
+/// Where to write the frames of a simulation instead of the terminal.
+#[derive(Debug, Default)]
+struct Images {
+    png_dir: Option<PathBuf>,
+    gif: Option<PathBuf>,
+}
+
+fn visualize(
+    (day, part): (u8, u8),
+    path: &PathBuf,
+    player: &Player,
+    images: &Images,
+) -> Result<(), String> {
+    let animate =
+        registry::animation(day).ok_or_else(|| format!("day {day} has nothing to visualize"))?;
+    let input = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    let frames = animate(part, &input)
+        .ok_or_else(|| format!("day {day} part {part} has nothing to visualize"))?
+        .map_err(|err| format!("{}:{err}", path.display()))?;
+
+    if images.png_dir.is_none() && images.gif.is_none() {
+        player
+            .play(frames, &mut io::stdout().lock())
+            .map_err(|err| format!("could not draw: {err}"))?;
+        return Ok(());
+    }
+
+    let create = |path: &Path| {
+        File::create(path).map_err(|err| format!("could not create {}: {err}", path.display()))
+    };
+    if let Some(dir) = &images.png_dir {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+    }
+    let mut gif = match &images.gif {
+        Some(path) => Some(GifWriter::new(BufWriter::new(create(path)?), player.fps)),
+        None => None,
+    };
+
+    let mut written = 0;
+    for (step, frame) in player.sample(frames) {
+        if let Some(dir) = &images.png_dir {
+            let path = dir.join(format!("day{day:02}-part{part}-{step:05}.png"));
+            export::write_png(&frame, BufWriter::new(create(&path)?))
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        }
+        if let Some(gif) = gif.as_mut() {
+            gif.write(&frame)
+                .map_err(|err| format!("could not write the gif: {err}"))?;
+        }
+        written += 1;
+    }
+
+    println!("Wrote {written} frames");
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut visualizing = false;
+    let mut player = Player::default();
+    let mut images = Images::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--visualize" => visualizing = true,
+            "--png" => {
+                visualizing = true;
+                images.png_dir = Some(PathBuf::from(args.next().unwrap_or_else(|| usage())));
+            }
+            "--gif" => {
+                visualizing = true;
+                images.gif = Some(PathBuf::from(args.next().unwrap_or_else(|| usage())));
+            }
+            "--fps" => {
+                let n = args.next().unwrap_or_else(|| usage());
+                player.fps = n
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid frame rate `{n}`"))?;
+            }
+            "--skip" => {
+                let n = args.next().unwrap_or_else(|| usage());
+                player.skip = n
+                    .parse::<usize>()
+                    .map_err(|_| format!("invalid step skip `{n}`"))?;
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional[..] {
+        [all] if all == "all" && !visualizing => {
+            // A day that fails doesn't stop the ones after it
+            let mut failed = 0;
+            for &puzzle in registry::PUZZLES {
+                let path = default_input(puzzle.day());
+                if path.exists() {
+                    let parts = (1..=puzzle.parts()).collect::<Vec<_>>();
+                    if let Err(err) = run_puzzle(puzzle, &parts, &path) {
+                        eprintln!("error: {err}");
+                        failed += 1;
+                    }
+                }
+            }
+
+            match failed {
+                0 => Ok(()),
+                1 => Err("1 day failed".to_string()),
+                n => Err(format!("{n} days failed")),
+            }
+        }
+        [day, part, ref rest @ ..] if rest.len() <= 1 => {
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day `{day}`"))?;
+            let part = part
+                .parse::<u8>()
+                .map_err(|_| format!("invalid part `{part}`"))?;
+            let puzzle = registry::find(day).ok_or_else(|| format!("no solver for day {day}"))?;
+            let path = rest
+                .first()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| default_input(day));
+
+            if visualizing {
+                visualize((day, part), &path, &player, &images)
+            } else {
+                run_puzzle(puzzle, &[part], &path)
+            }
+        }
+        _ => usage(),
+    }
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let path = match args {
+        [] => Path::new("answers.toml"),
+        [path] => Path::new(path),
+        _ => usage(),
+    };
+    let answers = Answers::load(path)?;
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    println!("Day Part       Time  Answer");
+    for &puzzle in registry::PUZZLES {
+        let path = default_input(puzzle.day());
+        let Ok(input) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        for part in 1..=puzzle.parts() {
+            let Some(check) = verify::check(puzzle, part, &input, &answers) else {
+                continue;
+            };
+
+            match check.status {
+                Status::Pass => passed += 1,
+                Status::Fail { .. } | Status::Invalid(_) => failed += 1,
+                Status::Unknown => unknown += 1,
+            }
+            println!("{check}");
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed, {unknown} without a known answer");
+    if failed > 0 {
+        return Err("some answers did not match".to_string());
+    }
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut repeat = 5;
+    let mut json = None;
+    let mut csv = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--repeat" => {
+                let n = args.next().unwrap_or_else(|| usage());
+                repeat = n
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("invalid repeat count `{n}`"))?;
+            }
+            "--json" => json = Some(args.next().unwrap_or_else(|| usage())),
+            "--csv" => csv = Some(args.next().unwrap_or_else(|| usage())),
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u8>()
+                        .map_err(|_| format!("invalid day `{arg}`"))?,
+                )
+            }
+            _ => usage(),
+        }
+    }
+
+    let puzzles = match day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| format!("no solver for day {day}"))?],
+        None => registry::PUZZLES.to_vec(),
+    };
+
+    let mut samples = Vec::new();
+    println!("Day  Phase        min     median       mean        max");
+    for puzzle in puzzles {
+        let path = default_input(puzzle.day());
+        let Ok(input) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let day_samples = bench::bench(puzzle, &input, repeat).map_err(|err| match err {
+            bench::BenchError::Parse(err) => format!("{}:{err}", path.display()),
+            err => format!("day {:02} {err}", puzzle.day()),
+        })?;
+        for sample in day_samples {
+            println!("{sample}");
+            samples.push(sample);
+        }
+    }
+
+    if let Some(path) = json {
+        fs::write(path, bench::to_json(&samples))
+            .map_err(|err| format!("could not write {path}: {err}"))?;
+    }
+    if let Some(path) = csv {
+        fs::write(path, bench::to_csv(&samples))
+            .map_err(|err| format!("could not write {path}: {err}"))?;
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+
+    let result = match args.get(1).map(String::as_str) {
+        Some("run") => run(&args[2..]),
+        Some("verify") => verify(&args[2..]),
+        Some("bench") => bench(&args[2..]),
+        _ => usage(),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
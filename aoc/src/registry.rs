@@ -0,0 +1,105 @@
+use common::{ParseError, Puzzle};
+use grid::Frames;
+
+/// Every day the runner knows how to solve, in order.
+pub const PUZZLES: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    PUZZLES.iter().copied().find(|puzzle| puzzle.day() == day)
+}
+
+/// Draws a day's simulation for one part, or returns `None` if that part has
+/// nothing to draw.
+pub type Animate = fn(u8, &str) -> Option<Result<Frames, ParseError>>;
+
+/// The days that can be watched with `aoc run --visualize`.
+pub const ANIMATIONS: &[(u8, Animate)] = &[
+    (6, day06::frames),
+    (14, day14::frames),
+    (15, day15::frames),
+    (16, day16::frames),
+    (18, day18::frames),
+];
+
+pub fn animation(day: u8) -> Option<Animate> {
+    ANIMATIONS
+        .iter()
+        .find(|(animated, _)| *animated == day)
+        .map(|&(_, animate)| animate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Answer;
+
+    #[test]
+    fn it_registers_every_day() {
+        for day in 1..=25 {
+            let puzzle = find(day).unwrap_or_else(|| panic!("day {day} is missing"));
+            assert_eq!(puzzle.parts(), if day == 25 { 1 } else { 2 });
+        }
+    }
+
+    #[test]
+    fn it_has_no_duplicates() {
+        for (i, a) in PUZZLES.iter().enumerate() {
+            for b in PUZZLES[i + 1..].iter() {
+                assert_ne!(a.day(), b.day());
+            }
+        }
+    }
+
+    #[test]
+    fn it_solves_through_the_registry() {
+        let puzzle = find(1).unwrap();
+        let input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        assert_eq!(puzzle.solve(1, input), Some(Ok(Answer::Int(11))));
+        assert_eq!(puzzle.solve(2, input), Some(Ok(Answer::Int(31))));
+        assert_eq!(puzzle.solve(3, input), None);
+    }
+
+    #[test]
+    fn it_finds_animations() {
+        let animate = animation(6).unwrap();
+        let input = "#.\n^.";
+
+        assert_eq!(animate(1, input).unwrap().unwrap().count(), 2);
+        assert_eq!(animate(2, input).unwrap().unwrap().count(), 0);
+        assert!(animate(3, input).is_none());
+        assert!(animate(1, "x").unwrap().is_err());
+        assert!(animation(1).is_none());
+    }
+}
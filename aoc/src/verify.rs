@@ -0,0 +1,228 @@
+use common::{Answer, Puzzle};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Known answers, keyed by day and part.
+///
+/// ```toml
+/// [day01]
+/// part1 = 1234
+/// part2 = "5,6,7"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(HashMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+        Self::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text.parse::<toml::Table>().map_err(|err| err.to_string())?;
+        let mut answers = HashMap::new();
+
+        for (day_key, parts) in table.iter() {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("unknown section `{day_key}`"))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{day_key}` should be a table"))?;
+
+            for (part_key, value) in parts.iter() {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse::<u8>().ok())
+                    .ok_or_else(|| format!("unknown key `{day_key}.{part_key}`"))?;
+                let value = match value {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.trim_end().to_string(),
+                    _ => {
+                        return Err(format!(
+                            "`{day_key}.{part_key}` should be an integer or a string"
+                        ))
+                    }
+                };
+
+                answers.insert((day, part), value);
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// Solved, but there is no known answer to compare against.
+    Unknown,
+    /// The input could not be parsed, or the part could not be solved from
+    /// it.
+    Invalid(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+pub fn check(puzzle: &dyn Puzzle, part: u8, input: &str, answers: &Answers) -> Option<Check> {
+    let start = Instant::now();
+    let solved = puzzle.solve(part, input)?;
+    let elapsed = start.elapsed();
+
+    let (answer, status) = match solved {
+        Ok(Answer::Error(err)) => (String::new(), Status::Invalid(err)),
+        Ok(answer) => {
+            let answer = answer.to_string();
+            let status = match answers.get(puzzle.day(), part) {
+                Some(expected) if expected == answer => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.to_string(),
+                },
+                None => Status::Unknown,
+            };
+            (answer, status)
+        }
+        Err(err) => (String::new(), Status::Invalid(err.to_string())),
+    };
+
+    Some(Check {
+        day: puzzle.day(),
+        part,
+        answer,
+        status,
+        elapsed,
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1e6;
+
+    if micros < 1e3 {
+        format!("{micros:.0}µs")
+    } else if micros < 1e6 {
+        format!("{:.1}ms", micros / 1e3)
+    } else {
+        format!("{:.2}s", micros / 1e6)
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match &self.status {
+            Status::Pass => "pass".to_string(),
+            Status::Fail { expected } => format!("FAIL (expected {expected})"),
+            Status::Unknown => "?".to_string(),
+            Status::Invalid(err) => format!("ERROR ({err})"),
+        };
+        // Multi-line answers are reported on one line.
+        let answer = self.answer.replace('\n', " / ");
+
+        write!(
+            f,
+            "{:>3} {:>4}  {:>9}  {answer}  {status}",
+            format!("{:02}", self.day),
+            self.part,
+            format_duration(self.elapsed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers = Answers::parse(
+            "[day01]
+part1 = 11
+part2 = \"31\"
+
+[day25]
+part1 = 3",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("11"));
+        assert_eq!(answers.get(1, 2), Some("31"));
+        assert_eq!(answers.get(25, 1), Some("3"));
+        assert_eq!(answers.get(25, 2), None);
+    }
+
+    #[test]
+    fn it_rejects_unknown_keys() {
+        assert!(Answers::parse("[first]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day01]\nfirst = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn it_checks_answers() {
+        let input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        let answers = Answers::parse("[day01]\npart1 = 11\npart2 = 30").unwrap();
+
+        let part1 = check(&day01::Day01, 1, input, &answers).unwrap();
+        assert_eq!(part1.status, Status::Pass);
+
+        let part2 = check(&day01::Day01, 2, input, &answers).unwrap();
+        assert_eq!(
+            part2.status,
+            Status::Fail {
+                expected: "30".to_string()
+            }
+        );
+
+        let invalid = check(&day01::Day01, 1, "3   4\n4", &answers).unwrap();
+        assert_eq!(
+            invalid.status,
+            Status::Invalid("2:1: expected two location IDs".to_string())
+        );
+
+        let unsolved = check(&day17::Day17, 1, "Program: 0,7", &answers).unwrap();
+        assert_eq!(
+            unsolved.status,
+            Status::Invalid("combo operand 7 is reserved, found at 0".to_string())
+        );
+        assert!(unsolved
+            .to_string()
+            .ends_with("ERROR (combo operand 7 is reserved, found at 0)"));
+
+        let empty = Answers::default();
+        let part1 = check(&day01::Day01, 1, input, &empty).unwrap();
+        assert_eq!(part1.status, Status::Unknown);
+    }
+
+    #[test]
+    fn it_formats_durations() {
+        assert_eq!(format_duration(Duration::from_micros(850)), "850µs");
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12.3ms");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50s");
+    }
+}
@@ -0,0 +1,120 @@
+use grid::Frame;
+use std::{
+    io::{self, Write},
+    iter, thread,
+    time::Duration,
+};
+
+/// Clears the screen before the first frame.
+const CLEAR: &str = "\x1b[2J";
+/// Moves the cursor to the top left, so each frame is drawn over the last.
+const HOME: &str = "\x1b[H";
+/// Clears everything after the cursor, in case the last frame was longer.
+const CLEAR_BELOW: &str = "\x1b[J";
+
+/// How a simulation is played in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Player {
+    /// Frames drawn per second, or 0 to draw them as fast as possible.
+    pub fps: u32,
+    /// Only every `skip`th frame is drawn. The last frame always is, and a
+    /// `skip` of 0 draws every frame like 1 does.
+    pub skip: usize,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Player { fps: 30, skip: 1 }
+    }
+}
+
+impl Player {
+    /// The frames to draw, with their step numbers.
+    pub fn sample(
+        &self,
+        frames: impl Iterator<Item = Frame>,
+    ) -> impl Iterator<Item = (usize, Frame)> {
+        let skip = self.skip.max(1);
+        let mut frames = frames.enumerate().peekable();
+
+        iter::from_fn(move || loop {
+            let (i, frame) = frames.next()?;
+            if i % skip == 0 || frames.peek().is_none() {
+                return Some((i, frame));
+            }
+        })
+    }
+
+    /// Draws each frame over the one before, returning how many were drawn.
+    pub fn play(
+        &self,
+        frames: impl Iterator<Item = Frame>,
+        out: &mut impl Write,
+    ) -> io::Result<usize> {
+        let delay = (self.fps > 0).then(|| Duration::from_secs(1) / self.fps);
+        let mut drawn = 0;
+
+        write!(out, "{CLEAR}")?;
+        for (_, frame) in self.sample(frames) {
+            write!(out, "{HOME}{}{CLEAR_BELOW}", frame.to_ansi())?;
+            out.flush()?;
+            drawn += 1;
+
+            if let Some(delay) = delay {
+                thread::sleep(delay);
+            }
+        }
+        writeln!(out)?;
+
+        Ok(drawn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::{Cell, Grid};
+
+    fn frames(n: usize) -> impl Iterator<Item = Frame> {
+        (0..n).map(|i| {
+            let ch = char::from_digit(i as u32, 10).unwrap();
+            Frame::new(Grid::new(2, 1, Cell::plain(ch)))
+        })
+    }
+
+    #[test]
+    fn it_draws_every_frame() {
+        let player = Player { fps: 0, skip: 1 };
+        let mut out = Vec::new();
+
+        assert_eq!(player.play(frames(3), &mut out).unwrap(), 3);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with(CLEAR));
+        assert_eq!(out.matches(HOME).count(), 3);
+        assert!(out.contains("00\x1b[0m"));
+        assert!(out.contains("22\x1b[0m"));
+    }
+
+    #[test]
+    fn it_skips_frames_but_not_the_last() {
+        let player = Player { fps: 0, skip: 3 };
+        let mut out = Vec::new();
+
+        assert_eq!(player.play(frames(8), &mut out).unwrap(), 4);
+
+        let out = String::from_utf8(out).unwrap();
+        for drawn in ["00", "33", "66", "77"] {
+            assert!(out.contains(drawn), "{drawn} was not drawn");
+        }
+        assert!(!out.contains("55"));
+    }
+
+    #[test]
+    fn it_draws_every_frame_with_a_skip_of_zero() {
+        let player = Player { fps: 0, skip: 0 };
+        let steps = player.sample(frames(3)).map(|(i, _)| i).collect::<Vec<_>>();
+
+        assert_eq!(steps, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Solves each day's example input through its library, the same way the
+//! runner does.
+
+use common::{Answer, Solution};
+
+fn solve<S: Solution>(input: &str) -> (Answer, Answer) {
+    let parsed = S::parse(input).unwrap();
+    (S::part1(&parsed), S::part2(&parsed))
+}
+
+macro_rules! example {
+    ($krate:ident::$day:ident, $part1:expr, $part2:expr) => {
+        #[test]
+        fn $krate() {
+            let input = include_str!(concat!("../../", stringify!($krate), "/src/bin/test.txt"));
+            let (part1, part2) = solve::<$krate::$day>(input);
+            assert_eq!(part1, $part1.into());
+            assert_eq!(part2, $part2.into());
+        }
+    };
+}
+
+example!(day01::Day01, 11, 31);
+example!(day02::Day02, 2, 4);
+example!(day03::Day03, 161, 161);
+example!(day04::Day04, 18, 9);
+example!(day05::Day05, 143, 123);
+example!(day06::Day06, 41, 6);
+example!(day07::Day07, 3749, 11387);
+example!(day08::Day08, 14, 34);
+example!(day09::Day09, 1928, 2858);
+example!(day10::Day10, 36, 81);
+example!(day11::Day11, 55312, 65601038650482u64);
+example!(day12::Day12, 1930, 1206);
+example!(day13::Day13, 480, 875318608908u64);
+example!(day15::Day15, 10092, 9021);
+example!(day16::Day16, 11048, 64);
+example!(day19::Day19, 6, 16);
+example!(day21::Day21, 126384, 154115708116294u64);
+example!(day22::Day22, 37327623, 24);
+example!(day23::Day23, 7, "co,de,ka,ta");
+
+// The example robots are in a smaller room than the real ones.
+#[test]
+fn day14() {
+    let input = include_str!("../../day14/src/bin/test.txt");
+    let robots = day14::robot::parse_in_room(input, 10, 6).unwrap();
+
+    assert_eq!(day14::part1::solve(&robots), 12.into());
+}
+
+// The example program doesn't output itself, so only part 1 is checked.
+#[test]
+fn day17() {
+    let input = include_str!("../../day17/src/bin/test.txt");
+    let computer = day17::Day17::parse(input).unwrap();
+
+    assert_eq!(day17::Day17::part1(&computer), "4,6,3,5,6,3,5,2,1,0".into());
+}
+
+// The example memory space is smaller than the real one.
+#[test]
+fn day18() {
+    let input = include_str!("../../day18/src/bin/test.txt");
+    let memory = day18::memory::parse_in_space(input, true).unwrap();
+
+    assert_eq!(day18::part1::solve(&memory), 22.into());
+    assert_eq!(day18::part2::solve(&memory), "6,1".into());
+}
+
+// The example track has no cheats that save 100 picoseconds.
+#[test]
+fn day20() {
+    let (part1, part2) = solve::<day20::Day20>(include_str!("../../day20/src/bin/test.txt"));
+
+    assert_eq!(part1, 0.into());
+    assert_eq!(part2, 0.into());
+}
+
+// The example wires aren't an adder, so only part 1 is checked.
+#[test]
+fn day24() {
+    let input = include_str!("../../day24/src/bin/test.txt");
+    let device = day24::Day24::parse(input).unwrap();
+
+    assert_eq!(day24::Day24::part1(&device), 2024.into());
+}
+
+#[test]
+fn day25() {
+    let input = include_str!("../../day25/src/bin/test.txt");
+    let schematics = day25::Day25::parse(input).unwrap();
+
+    assert_eq!(day25::Day25::part1(&schematics), 3.into());
+}
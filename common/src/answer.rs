@@ -0,0 +1,101 @@
+use std::fmt;
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    Lines(Vec<String>),
+    /// A part that couldn't be solved from the input, and why.
+    Error(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+            Answer::Error(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+// Values that don't fit in an `i64` are kept as their decimal string.
+macro_rules! impl_from_wide_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    i64::try_from(n)
+                        .map(Answer::Int)
+                        .unwrap_or_else(|_| Answer::Str(n.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, i8, i16, i32, i64);
+impl_from_wide_int!(u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::Lines(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_integers() {
+        assert_eq!(Answer::from(42u32), Answer::Int(42));
+        assert_eq!(Answer::from(-7i32), Answer::Int(-7));
+        assert_eq!(Answer::from(123usize), Answer::Int(123));
+    }
+
+    #[test]
+    fn it_keeps_wide_integers_exact() {
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Str("18446744073709551615".to_string())
+        );
+        assert_eq!(Answer::from(7u128), Answer::Int(7));
+    }
+
+    #[test]
+    fn it_displays_answers() {
+        assert_eq!(Answer::from(1234u64).to_string(), "1234");
+        assert_eq!(Answer::from("co,de,ka,ta").to_string(), "co,de,ka,ta");
+        assert_eq!(
+            Answer::from(vec!["#..".to_string(), ".#.".to_string()]).to_string(),
+            "#..\n.#."
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::{process, str::FromStr};
+
+/// The input path, which is the first argument unless that's a flag.
+pub fn path<'a>(args: &'a [String], default: &'a str) -> &'a str {
+    args.get(1)
+        .map(String::as_str)
+        .filter(|arg| !arg.starts_with("--"))
+        .unwrap_or(default)
+}
+
+/// The value after `flag`, or `None` if it isn't given. A flag with nothing
+/// after it, or another flag, is an error.
+pub fn value<'a>(args: &'a [String], flag: &str) -> Result<Option<&'a str>, String> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+
+    match args.get(i + 1) {
+        Some(value) if !value.starts_with("--") => Ok(Some(value)),
+        _ => Err(format!("{flag} needs a value")),
+    }
+}
+
+/// The value after `flag` as a number, or `None` if it isn't given.
+pub fn number<T: FromStr>(args: &[String], flag: &str) -> Result<Option<T>, String> {
+    value(args, flag)?
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("{flag} needs a number, found `{value}`"))
+        })
+        .transpose()
+}
+
+/// Prints `err` and how to run the binary, then exits.
+pub fn usage(err: &str, usage: &str) -> ! {
+    eprintln!("error: {err}\nusage: {usage}");
+    process::exit(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn it_finds_the_path() {
+        assert_eq!(
+            path(&args("bin test.txt --list 3"), "input.txt"),
+            "test.txt"
+        );
+        assert_eq!(path(&args("bin --list 3"), "input.txt"), "input.txt");
+        assert_eq!(path(&args("bin"), "input.txt"), "input.txt");
+    }
+
+    #[test]
+    fn it_reads_flag_values() {
+        let given = args("bin test.txt --list 3 --seed x");
+
+        assert_eq!(value(&given, "--list"), Ok(Some("3")));
+        assert_eq!(value(&given, "--sample"), Ok(None));
+        assert_eq!(number::<u64>(&given, "--list"), Ok(Some(3)));
+        assert_eq!(
+            number::<u64>(&given, "--seed"),
+            Err("--seed needs a number, found `x`".to_string())
+        );
+    }
+
+    #[test]
+    fn it_rejects_flags_without_values() {
+        assert_eq!(
+            value(&args("bin test.txt --list"), "--list"),
+            Err("--list needs a value".to_string())
+        );
+        assert_eq!(
+            number::<u64>(&args("bin --list --sample 3"), "--list"),
+            Err("--list needs a value".to_string())
+        );
+    }
+}
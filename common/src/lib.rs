@@ -0,0 +1,8 @@
+mod answer;
+pub mod args;
+pub mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use solution::{Puzzle, Solution, Timings};
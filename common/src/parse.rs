@@ -0,0 +1,129 @@
+use std::{fmt, str::FromStr};
+
+/// A problem in a puzzle input, with the 1-based line and column it was
+/// found at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error pointing at `token`, which must be a slice of `input`.
+    pub fn at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    /// Moves an error found while parsing a single line to `line` of the
+    /// whole input.
+    pub fn on_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+
+    /// An error for input that ended before `expected` was found.
+    pub fn eof(input: &str, expected: &str) -> Self {
+        ParseError::at(
+            input,
+            &input[input.len()..],
+            format!("expected {expected}, found end of input"),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, format!("expected a number, found `{token}`")))
+}
+
+/// Splits `token`, a slice of `input`, at the first `delimiter`.
+pub fn split_once<'a>(
+    input: &str,
+    token: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    token.split_once(delimiter).ok_or_else(|| {
+        ParseError::at(
+            input,
+            token,
+            format!("expected `{delimiter}` in `{}`", token.trim()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_locates_tokens() {
+        let input = "ab\ncd ef\n";
+        let error = ParseError::at(input, &input[6..8], "bad");
+
+        assert_eq!(error, ParseError::new(2, 4, "bad"));
+        assert_eq!(error.to_string(), "2:4: bad");
+    }
+
+    #[test]
+    fn it_moves_errors_to_a_line() {
+        let line = "12: x";
+        let error = number::<u8>(line, &line[4..]).unwrap_err().on_line(7);
+
+        assert_eq!(error.to_string(), "7:5: expected a number, found `x`");
+    }
+
+    #[test]
+    fn it_reports_the_end_of_input() {
+        let error = ParseError::eof("ab\ncd", "a towel");
+
+        assert_eq!(
+            error,
+            ParseError::new(2, 3, "expected a towel, found end of input")
+        );
+    }
+
+    #[test]
+    fn it_parses_numbers() {
+        let input = "p=1,x";
+        let (_, value) = input.split_once('=').unwrap();
+        let (x, y) = split_once(input, value, ",").unwrap();
+
+        assert_eq!(number::<i32>(input, x), Ok(1));
+        assert_eq!(
+            number::<i32>(input, y),
+            Err(ParseError::new(1, 5, "expected a number, found `x`"))
+        );
+        assert_eq!(
+            split_once(input, y, ","),
+            Err(ParseError::new(1, 5, "expected `,` in `x`"))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Answer, ParseError};
+use std::time::{Duration, Instant};
+
+/// A day's puzzle. The input is parsed once and both parts are solved from
+/// the parsed form, so the parse cost can be measured on its own.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The number of parts the day has. Day 25 only has one.
+    const PARTS: u8 = 2;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Answer;
+}
+
+/// How long each phase of a single run took.
+#[derive(Debug)]
+pub struct Timings {
+    pub parse: Duration,
+    /// The answer and solve time of each part, in order.
+    pub parts: Vec<(Answer, Duration)>,
+}
+
+/// Object safe view of a [`Solution`], so days with different input types
+/// can be kept in one list.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> u8;
+
+    /// Parses `input` and solves `part`, or returns `None` if the day has no
+    /// such part.
+    fn solve(&self, part: u8, input: &str) -> Option<Result<Answer, ParseError>>;
+
+    /// Parses `input` once and solves every part from it, timing each phase.
+    fn timed(&self, input: &str) -> Result<Timings, ParseError>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn solve(&self, part: u8, input: &str) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(S::parse(input).map(|parsed| S::part1(&parsed))),
+            2 if S::PARTS >= 2 => Some(S::parse(input).map(|parsed| S::part2(&parsed))),
+            _ => None,
+        }
+    }
+
+    fn timed(&self, input: &str) -> Result<Timings, ParseError> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let mut parts = Vec::new();
+        for part in 1..=S::PARTS {
+            let start = Instant::now();
+            let answer = if part == 1 {
+                S::part1(&parsed)
+            } else {
+                S::part2(&parsed)
+            };
+            parts.push((answer, start.elapsed()));
+        }
+
+        Ok(Timings { parse, parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 1;
+        type Input<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            input
+                .lines()
+                .map(|line| crate::parse::number(input, line))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Answer {
+            input
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+                .into()
+        }
+    }
+
+    struct Single;
+
+    impl Solution for Single {
+        const DAY: u8 = 25;
+        const PARTS: u8 = 1;
+        type Input<'a> = &'a str;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Answer {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn it_solves_both_parts() {
+        let puzzle: &dyn Puzzle = &Sums;
+
+        assert_eq!(puzzle.day(), 1);
+        assert_eq!(puzzle.solve(1, "1\n2\n3"), Some(Ok(Answer::Int(6))));
+        assert_eq!(
+            puzzle.solve(2, "1\n2\n3"),
+            Some(Ok(Answer::Str("1,2,3".to_string())))
+        );
+    }
+
+    #[test]
+    fn it_skips_missing_parts() {
+        let puzzle: &dyn Puzzle = &Single;
+
+        assert_eq!(puzzle.parts(), 1);
+        assert_eq!(puzzle.solve(1, "abc"), Some(Ok(Answer::Int(3))));
+        assert_eq!(puzzle.solve(2, "abc"), None);
+        assert_eq!(puzzle.solve(3, "abc"), None);
+    }
+
+    #[test]
+    fn it_reports_parse_errors() {
+        let puzzle: &dyn Puzzle = &Sums;
+
+        assert_eq!(
+            puzzle.solve(1, "1\ntwo"),
+            Some(Err(ParseError::new(2, 1, "expected a number, found `two`")))
+        );
+        assert!(Sums.timed("x").is_err());
+    }
+
+    #[test]
+    fn it_times_every_part() {
+        let timings = Sums.timed("1\n2\n3").unwrap();
+        let answers = timings.parts.into_iter().map(|(answer, _)| answer);
+
+        assert_eq!(
+            answers.collect::<Vec<_>>(),
+            vec![Answer::Int(6), Answer::Str("1,2,3".to_string())]
+        );
+        assert_eq!(Single.timed("abc").unwrap().parts.len(), 1);
+    }
+}
@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod locations;
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = locations::Locations;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        locations::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
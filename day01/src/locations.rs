@@ -0,0 +1,144 @@
+use common::{parse, ParseError};
+
+/// Both lists of location IDs, each sorted from smallest to largest.
+#[derive(Debug, PartialEq)]
+pub struct Locations {
+    left: Vec<u32>,
+    right: Vec<u32>,
+}
+
+impl Locations {
+    fn new(list: &str) -> Result<Self, ParseError> {
+        let mut loc = Locations {
+            left: vec![],
+            right: vec![],
+        };
+
+        for line in list.lines().filter(|line| !line.trim().is_empty()) {
+            let mut parts = line.split_whitespace();
+            let (Some(left), Some(right)) = (parts.next(), parts.next()) else {
+                return Err(ParseError::at(list, line, "expected two location IDs"));
+            };
+
+            loc.left.push(parse::number(list, left)?);
+            loc.right.push(parse::number(list, right)?);
+        }
+
+        loc.left.sort_unstable();
+        loc.right.sort_unstable();
+
+        Ok(loc)
+    }
+
+    /// The total distance between the lists, pairing IDs up in order.
+    pub fn sum_delta(&self) -> u32 {
+        let delta: Vec<u32> = self
+            .left
+            .iter()
+            .zip(self.right.iter())
+            .map(|(l, r)| l.abs_diff(*r))
+            .collect();
+
+        delta.into_iter().sum()
+    }
+
+    /// Each left ID multiplied by how often it appears in the right list.
+    pub fn similarity_score(&self) -> u32 {
+        let mut score = 0;
+
+        for &n in self.left.iter() {
+            let instances: u32 = self
+                .right
+                .iter()
+                .filter(|&&x| x == n)
+                .count()
+                .try_into()
+                .unwrap();
+            score += instances * n;
+        }
+
+        score
+    }
+}
+
+pub fn parse(input: &str) -> Result<Locations, ParseError> {
+    Locations::new(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_an_empty_location() {
+        let loc = Locations::new("").unwrap();
+
+        assert_eq!(
+            loc,
+            Locations {
+                left: vec![],
+                right: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn it_parses_and_sorts_the_input() {
+        let test_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        let loc = Locations::new(test_input).unwrap();
+
+        assert_eq!(
+            loc,
+            Locations {
+                left: vec![1, 2, 3, 3, 3, 4],
+                right: vec![3, 3, 3, 4, 5, 9],
+            }
+        )
+    }
+
+    #[test]
+    fn it_sums_the_differences() {
+        let test_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        let loc = Locations::new(test_input).unwrap();
+
+        assert_eq!(loc.sum_delta(), 11);
+    }
+
+    #[test]
+    fn it_rejects_malformed_lines() {
+        assert_eq!(
+            Locations::new("3   4\n4").unwrap_err().to_string(),
+            "2:1: expected two location IDs"
+        );
+        assert_eq!(
+            Locations::new("3   4\n4   -3").unwrap_err().to_string(),
+            "2:5: expected a number, found `-3`"
+        );
+    }
+
+    #[test]
+    fn it_calculates_similarity_score() {
+        let test_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        let loc = Locations::new(test_input).unwrap();
+
+        assert_eq!(loc.similarity_score(), 31);
+    }
+}
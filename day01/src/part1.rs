@@ -0,0 +1,6 @@
+use crate::locations::Locations;
+use common::Answer;
+
+pub fn solve(locations: &Locations) -> Answer {
+    locations.sum_delta().into()
+}
@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod report;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        report::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
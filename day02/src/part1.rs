@@ -0,0 +1,16 @@
+use crate::report::{check_report, Status};
+use common::Answer;
+
+pub fn solve(reports: &[&str]) -> Answer {
+    let mut safe_count = 0;
+
+    for &report in reports {
+        let status = check_report(report.into());
+
+        if status == Status::Safe {
+            safe_count += 1;
+        }
+    }
+
+    safe_count.into()
+}
@@ -0,0 +1,65 @@
+use crate::report::{check_report, Status};
+use common::Answer;
+
+fn generate_subsets(report: String) -> Vec<String> {
+    let mut subsets = vec![];
+    let levels: Vec<i32> = report
+        .split_whitespace()
+        .filter_map(|n| n.parse().ok())
+        .collect();
+
+    // Generate subsets where 1 element is removed
+    for i in 0..levels.len() {
+        let mut subset = levels.clone();
+        subset.remove(i);
+        let stringified = subset
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        subsets.push(stringified);
+    }
+
+    subsets
+}
+
+pub fn solve(reports: &[&str]) -> Answer {
+    let mut safe_count = 0;
+
+    for &report in reports {
+        let status = check_report(report.into());
+
+        match status {
+            Status::Safe => safe_count += 1,
+            Status::Unsafe => {
+                let subsets = generate_subsets(report.into());
+
+                // Generate subsets with 1 element removed and re-check
+                for subset in subsets {
+                    match check_report(subset) {
+                        Status::Safe => {
+                            safe_count += 1;
+                            break;
+                        }
+                        Status::Unsafe => continue,
+                    }
+                }
+            }
+        }
+    }
+
+    safe_count.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_subsets() {
+        assert_eq!(
+            generate_subsets(String::from("1 2 7")),
+            vec!["2 7", "1 7", "1 2"]
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use common::{parse, ParseError};
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Status {
+    Safe,
+    Unsafe,
+}
+
+/// Whether the levels all rise or all fall, by between 1 and 3 each step.
+pub(crate) fn check_report(report: String) -> Status {
+    let levels: Vec<i32> = report
+        .split_whitespace()
+        .filter_map(|n| n.parse().ok())
+        .collect();
+
+    // Check that the absolute difference between adjacent levels is 1 <= x <= 3
+    let check_levels = levels.windows(2).all(|vals| {
+        let diff = vals[0].abs_diff(vals[1]);
+        (1..=3).contains(&diff)
+    });
+
+    if !check_levels {
+        return Status::Unsafe;
+    }
+
+    // Calculate the sign of adjacent levels
+    let differences: Vec<i32> = levels
+        .windows(2)
+        .map(|vals| (vals[1] - vals[0]).signum())
+        .collect();
+
+    // Make sure they're all the same
+    let check_differences = differences.iter().all(|x| *x == differences[0]);
+
+    if check_levels && check_differences {
+        Status::Safe
+    } else {
+        Status::Unsafe
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    let reports = input.lines().collect::<Vec<_>>();
+
+    for level in reports.iter().flat_map(|report| report.split_whitespace()) {
+        parse::number::<i32>(input, level)?;
+    }
+
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_checks_safe_reports() {
+        assert_eq!(check_report(String::from("7 6 4 2 1")), Status::Safe);
+        assert_eq!(check_report(String::from("1 3 6 7 9")), Status::Safe);
+    }
+
+    #[test]
+    fn it_checks_unsafe_reports() {
+        assert_eq!(check_report(String::from("1 2 7 8 9")), Status::Unsafe);
+        assert_eq!(check_report(String::from("9 7 6 2 1")), Status::Unsafe);
+        assert_eq!(check_report(String::from("1 3 2 4 5")), Status::Unsafe);
+        assert_eq!(check_report(String::from("8 6 4 4 1")), Status::Unsafe);
+    }
+
+    #[test]
+    fn it_rejects_bad_levels() {
+        assert_eq!(
+            parse("7 6 4 2 1\n1 3 x 7 9").unwrap_err().to_string(),
+            "2:5: expected a number, found `x`"
+        );
+    }
+}
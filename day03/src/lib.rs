@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod memory;
+pub mod part1;
+pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = Vec<memory::Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        memory::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
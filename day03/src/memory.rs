@@ -0,0 +1,120 @@
+use common::ParseError;
+
+/// A well formed instruction found in the corrupted memory.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+// The memory is corrupted, so anything that isn't a well formed instruction
+// is skipped rather than reported.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut results = vec![];
+
+    for (i, _) in input.char_indices() {
+        let substring = &input[i..input.len()];
+
+        // Check enable/disable
+        if substring.starts_with("don't()") {
+            results.push(Instruction::Dont);
+        } else if substring.starts_with("do()") {
+            results.push(Instruction::Do);
+        }
+
+        // Check if substring starts with `mul(`
+        if substring.starts_with("mul(") {
+            // Check for numbers, comma, and ending `)`
+            let mut end_idx = 4;
+            while end_idx < 12 {
+                if let Some(c) = substring.chars().nth(end_idx) {
+                    if !c.is_ascii_digit() && c != ',' && c != ')' {
+                        break;
+                    }
+
+                    if c == ')' {
+                        end_idx += 1;
+                        break;
+                    }
+
+                    end_idx += 1;
+                } else {
+                    break;
+                }
+            }
+
+            if let Some(c) = substring.chars().nth(end_idx - 1) {
+                if c == ')' && is_multiplication(&substring[..end_idx]) {
+                    results.push(multiplication(&substring[..end_idx]));
+                }
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+fn is_multiplication(instruction: &str) -> bool {
+    let args = &instruction[4..instruction.len() - 1];
+
+    args.split_once(",").is_some_and(|(x, y)| {
+        [x, y]
+            .iter()
+            .all(|n| (1..=3).contains(&n.len()) && n.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+fn multiplication(input: &str) -> Instruction {
+    let digits = &input[4..input.len() - 1].split(",").collect::<Vec<&str>>();
+    let x = digits[0].parse::<i32>().unwrap();
+    let y = digits[1].parse::<i32>().unwrap();
+
+    Instruction::Mul(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses() {
+        assert_eq!(
+            parse("mul(123,456)").unwrap(),
+            vec![Instruction::Mul(123, 456)]
+        );
+        assert_eq!(
+            parse("do()mul(123,456)").unwrap(),
+            vec![Instruction::Do, Instruction::Mul(123, 456)]
+        );
+        assert_eq!(
+            parse("don't()mul(123,456)").unwrap(),
+            vec![Instruction::Dont, Instruction::Mul(123, 456)]
+        );
+    }
+
+    #[test]
+    fn it_parses_a_line() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        assert_eq!(
+            parse(input).unwrap(),
+            vec![
+                Instruction::Mul(2, 4),
+                Instruction::Dont,
+                Instruction::Mul(5, 5),
+                Instruction::Mul(11, 8),
+                Instruction::Do,
+                Instruction::Mul(8, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_skips_malformed_instructions() {
+        assert_eq!(
+            parse("mul(1,,2)mul(,3)mul(1234,5)mul(2,3)do(1)").unwrap(),
+            vec![Instruction::Mul(2, 3)]
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use crate::memory::Instruction;
+use common::Answer;
+
+pub fn solve(instructions: &[Instruction]) -> Answer {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(x, y) => x * y,
+            _ => 0,
+        })
+        .sum::<i32>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory;
+
+    #[test]
+    fn it_multiplies_everything() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+        assert_eq!(solve(&memory::parse(input).unwrap()), 161.into());
+    }
+}
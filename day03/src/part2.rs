@@ -0,0 +1,33 @@
+use crate::memory::Instruction;
+use common::Answer;
+
+/// Adds up the multiplications, skipping any after a `don't()` until the
+/// next `do()`.
+pub fn solve(instructions: &[Instruction]) -> Answer {
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(x, y) if enabled => sum += x * y,
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+
+    sum.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory;
+
+    #[test]
+    fn it_multiplies_enabled_instructions() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        assert_eq!(solve(&memory::parse(input).unwrap()), 48.into());
+    }
+}
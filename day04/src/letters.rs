@@ -0,0 +1,50 @@
+use common::ParseError;
+use grid::Grid;
+
+/// The word search, one letter per tile.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_chars(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::Point;
+
+    #[test]
+    fn it_parses_a_line() {
+        let letters = parse("XMAS").unwrap();
+
+        assert_eq!(letters[Point::new(0, 0)], 'X');
+        assert_eq!(letters[Point::new(1, 0)], 'M');
+        assert_eq!(letters[Point::new(2, 0)], 'A');
+        assert_eq!(letters[Point::new(3, 0)], 'S');
+    }
+
+    #[test]
+    fn it_parses_multiple_lines() {
+        let input = "AB
+CD
+EF"
+        .to_string();
+        let letters = parse(&input).unwrap();
+
+        assert_eq!(letters[Point::new(0, 0)], 'A');
+        assert_eq!(letters[Point::new(1, 0)], 'B');
+        assert_eq!(letters[Point::new(0, 1)], 'C');
+        assert_eq!(letters[Point::new(1, 1)], 'D');
+        assert_eq!(letters[Point::new(0, 2)], 'E');
+        assert_eq!(letters[Point::new(1, 2)], 'F');
+    }
+
+    #[test]
+    fn it_gets_n_rows_cols() {
+        let input = parse(
+            "MMMSXXMASM
+MSAMXMSMSA",
+        )
+        .unwrap();
+
+        assert_eq!((input.height(), input.width()), (2, 10));
+    }
+}
@@ -0,0 +1,25 @@
+use common::{Answer, ParseError, Solution};
+use grid::Grid;
+
+pub mod letters;
+pub mod part1;
+pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        letters::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,128 @@
+use common::Answer;
+use grid::{Grid, Point};
+
+fn search(input: &Grid<char>) -> i32 {
+    let mut count = 0;
+
+    for (point, &ch) in input.iter() {
+        if ch != 'X' {
+            continue;
+        }
+
+        // Search rows, columns and diagonals in both directions
+        for offset in Point::NEIGHBOURS8 {
+            let found = "MAS"
+                .chars()
+                .zip(1..)
+                .all(|(letter, n)| input.get(point + offset * n) == Some(&letter));
+
+            if found {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn solve(letters: &Grid<char>) -> Answer {
+    search(letters).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::letters::parse;
+
+    #[test]
+    fn it_searches_by_row_forward() {
+        let input = parse("MMMSXXMASM").unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_by_row_reverse() {
+        let input = parse("MMMSXSAMXM").unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_by_col_down() {
+        let input = parse(
+            "X
+M
+A
+S",
+        )
+        .unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_by_col_up() {
+        let input = parse(
+            "S
+A
+M
+X",
+        )
+        .unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_diag_forward_down() {
+        let input = parse(
+            "XMMM
+MMMM
+MMAM
+MMMS",
+        )
+        .unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_diag_reverse_down() {
+        let input = parse(
+            "IIIX
+IIMI
+IAII
+SIII",
+        )
+        .unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_diag_forward_up() {
+        let input = parse(
+            "IIIS
+IIAI
+IMII
+XIII",
+        )
+        .unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+
+    #[test]
+    fn it_searches_diag_reverse_up() {
+        let input = parse(
+            "SIII
+IAII
+IIMI
+IIIX",
+        )
+        .unwrap();
+
+        assert_eq!(search(&input), 1);
+    }
+}
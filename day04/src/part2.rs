@@ -0,0 +1,79 @@
+use common::Answer;
+use grid::{Grid, Point};
+
+fn search(input: &Grid<char>) -> i32 {
+    let mut count = 0;
+
+    for (point, &ch) in input.iter() {
+        if ch != 'A' {
+            continue;
+        }
+
+        // Get the 4 diagonal characters
+        let ch_up_left = input.get(point + Point::new(-1, -1));
+        let ch_up_right = input.get(point + Point::new(1, -1));
+        let ch_down_left = input.get(point + Point::new(-1, 1));
+        let ch_down_right = input.get(point + Point::new(1, 1));
+
+        if let (Some(ch_up_left), Some(ch_up_right), Some(ch_down_left), Some(ch_down_right)) =
+            (ch_up_left, ch_up_right, ch_down_left, ch_down_right)
+        {
+            if (*ch_up_left == 'M' || *ch_up_left == 'S')
+                && (*ch_up_right == 'M' || *ch_up_right == 'S')
+                && (*ch_down_left == 'M' || *ch_down_left == 'S')
+                && (*ch_down_right == 'M' || *ch_down_right == 'S')
+                && (*ch_up_left != *ch_down_right)
+                && (*ch_up_right != *ch_down_left)
+            {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn solve(letters: &Grid<char>) -> Answer {
+    search(letters).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::letters::parse;
+
+    #[test]
+    fn it_searches_x_diagonals() {
+        let input = parse(
+            "M.M
+.A.
+S.S",
+        )
+        .unwrap();
+        assert_eq!(search(&input), 1);
+
+        let input = parse(
+            "M.S
+.A.
+M.S",
+        )
+        .unwrap();
+        assert_eq!(search(&input), 1);
+
+        let input = parse(
+            "S.S
+.A.
+M.M",
+        )
+        .unwrap();
+        assert_eq!(search(&input), 1);
+
+        let input = parse(
+            "S.M
+.A.
+S.M",
+        )
+        .unwrap();
+        assert_eq!(search(&input), 1);
+    }
+}
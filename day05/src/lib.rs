@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod manual;
+pub mod part1;
+pub mod part2;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = manual::Manual;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        manual::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,188 @@
+use common::{parse, ParseError};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Status {
+    Valid,
+    Invalid,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct ValidationResult {
+    pub(crate) status: Status,
+    pub(crate) n_idx: Option<usize>,
+    pub(crate) invalid_idx: Option<usize>,
+}
+
+/// The page ordering rules and the updates to check against them.
+#[derive(Debug)]
+pub struct Manual {
+    pub(crate) rules: HashMap<u8, Vec<u8>>,
+    pub(crate) updates: Vec<Vec<u8>>,
+}
+
+pub(crate) fn parse_rules(input: &str) -> Result<HashMap<u8, Vec<u8>>, ParseError> {
+    let mut rules = HashMap::new();
+
+    for line in input.lines().filter(|l| l.contains("|")) {
+        let (left, right) = parse::split_once(input, line, "|")?;
+        let left = parse::number::<u8>(input, left.trim())?;
+        let right = parse::number::<u8>(input, right.trim())?;
+
+        rules.entry(left).or_insert_with(Vec::new).push(right);
+    }
+
+    Ok(rules)
+}
+
+pub(crate) fn parse_updates(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    input
+        .lines()
+        .filter(|line| line.contains(","))
+        .map(|line| {
+            line.split(",")
+                .map(|n| parse::number::<u8>(input, n.trim()))
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn validate_update(rules: &HashMap<u8, Vec<u8>>, input: &[u8]) -> ValidationResult {
+    // For each number in input, check its rules
+    for (i, x) in input.iter().enumerate() {
+        if let Some(rule) = rules.get(x) {
+            // For each number in the rule, check its position
+            for n in rule {
+                if let Some(idx) = input.iter().position(|y| y == n) {
+                    if idx < i {
+                        return ValidationResult {
+                            status: Status::Invalid,
+                            n_idx: Some(i),
+                            invalid_idx: Some(idx),
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    ValidationResult {
+        status: Status::Valid,
+        n_idx: None,
+        invalid_idx: None,
+    }
+}
+
+pub(crate) fn get_code(input: Vec<u8>) -> u16 {
+    let mid_idx = input.len() / 2;
+    input[mid_idx] as u16
+}
+
+pub fn parse(input: &str) -> Result<Manual, ParseError> {
+    Ok(Manual {
+        rules: parse_rules(input)?,
+        updates: parse_updates(input)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_rules() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+
+75,47,61,53,29
+97,61,53,29,13"
+            .to_string();
+
+        assert_eq!(
+            parse_rules(&input).unwrap(),
+            HashMap::from([(47, vec![53]), (97, vec![13, 61, 47]), (75, vec![29]),])
+        );
+    }
+
+    #[test]
+    fn it_parses_updates() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+
+75,47,61,53,29
+97,61,53,29,13"
+            .to_string();
+
+        assert_eq!(
+            parse_updates(&input).unwrap(),
+            vec![vec![75, 47, 61, 53, 29], vec![97, 61, 53, 29, 13],]
+        );
+    }
+
+    #[test]
+    fn it_gets_the_code() {
+        assert_eq!(get_code(vec![75, 47, 61, 53, 29]), 61);
+        assert_eq!(get_code(vec![75, 29, 13]), 29);
+    }
+
+    #[test]
+    fn it_checks_updates() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47"
+            .to_string();
+
+        let rules = parse_rules(&input).unwrap();
+        let updates = parse_updates(&input).unwrap();
+
+        assert_eq!(validate_update(&rules, &updates[0]).status, Status::Valid);
+        assert_eq!(validate_update(&rules, &updates[1]).status, Status::Valid);
+        assert_eq!(validate_update(&rules, &updates[2]).status, Status::Valid);
+        assert_eq!(validate_update(&rules, &updates[3]).status, Status::Invalid);
+        assert_eq!(validate_update(&rules, &updates[4]).status, Status::Invalid);
+        assert_eq!(validate_update(&rules, &updates[5]).status, Status::Invalid);
+    }
+
+    #[test]
+    fn it_rejects_bad_page_numbers() {
+        assert_eq!(
+            parse("47|53\n97|x3\n\n75,47").unwrap_err().to_string(),
+            "2:4: expected a number, found `x3`"
+        );
+        assert_eq!(
+            parse("47|53\n\n75,47,,61").unwrap_err().to_string(),
+            "3:7: expected a number, found ``"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::manual::{get_code, validate_update, Manual, Status};
+use common::Answer;
+
+pub fn solve(manual: &Manual) -> Answer {
+    manual
+        .updates
+        .iter()
+        .filter(|update| validate_update(&manual.rules, update).status == Status::Valid)
+        .map(|update| get_code(update.clone()))
+        .sum::<u16>()
+        .into()
+}
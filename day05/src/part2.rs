@@ -0,0 +1,138 @@
+use crate::manual::{get_code, validate_update, Manual, Status};
+use common::Answer;
+use std::collections::HashMap;
+
+#[cfg(test)]
+fn get_invalid_updates(rules: &HashMap<u8, Vec<u8>>, input: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut invalids = Vec::new();
+
+    for line in input {
+        if validate_update(rules, line).status == Status::Invalid {
+            invalids.push(line.clone());
+        }
+    }
+
+    invalids
+}
+
+fn reorder(rules: &HashMap<u8, Vec<u8>>, input: &[u8]) -> Vec<u8> {
+    let mut reordered = input.to_vec();
+
+    loop {
+        let validation_result = validate_update(rules, &reordered);
+
+        if validation_result.status == Status::Valid {
+            break;
+        }
+
+        reordered.swap(
+            validation_result.n_idx.unwrap(),
+            validation_result.invalid_idx.unwrap(),
+        );
+    }
+
+    reordered
+}
+
+pub fn solve(manual: &Manual) -> Answer {
+    manual
+        .updates
+        .iter()
+        .filter(|update| validate_update(&manual.rules, update).status == Status::Invalid)
+        .map(|update| reorder(&manual.rules, update))
+        .map(get_code)
+        .sum::<u16>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manual::{parse_rules, parse_updates};
+
+    #[test]
+    fn it_gets_invalid_updates() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47"
+            .to_string();
+
+        let rules = parse_rules(&input).unwrap();
+        let updates = parse_updates(&input).unwrap();
+
+        assert_eq!(
+            get_invalid_updates(&rules, &updates),
+            vec![
+                vec![75, 97, 47, 61, 53],
+                vec![61, 13, 29],
+                vec![97, 13, 75, 29, 47],
+            ]
+        )
+    }
+
+    #[test]
+    fn it_reorders_invalid_inputs() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47"
+            .to_string();
+
+        let rules = parse_rules(&input).unwrap();
+        let updates = parse_updates(&input).unwrap();
+        let invalids = get_invalid_updates(&rules, &updates);
+
+        assert_eq!(reorder(&rules, &invalids[0]), vec![97, 75, 47, 61, 53]);
+        assert_eq!(reorder(&rules, &invalids[1]), vec![61, 29, 13]);
+        assert_eq!(reorder(&rules, &invalids[2]), vec![97, 75, 47, 29, 13]);
+    }
+}
@@ -0,0 +1,25 @@
+// Lists every tile where an obstacle would trap the guard in a loop, with the
+// length of the loop, and draws them on the map. `--colour` draws it for a
+// terminal.
+// To run: `cargo run --bin loops -- src/bin/input.txt --colour`
+
+use common::{args, Solution};
+use day06::{loops, Day06};
+use std::{env, fs};
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let input = fs::read_to_string(&path).expect("to read file");
+    let guard = Day06::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+
+    let found = loops::loop_obstacles(&guard);
+    let frame = loops::frame(&guard, &found);
+
+    println!("{}\n", loops::report(&found));
+    if args.iter().any(|arg| arg == "--colour") {
+        println!("{}", frame.to_ansi());
+    } else {
+        println!("{frame}");
+    }
+}
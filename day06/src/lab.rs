@@ -0,0 +1,487 @@
+use common::ParseError;
+use grid::{Cell, Colour, Direction, Frame, Grid, Point};
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq)]
+enum Position {
+    Open,
+    Obstacle,
+    Exit,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Map {
+    pub(crate) obstacles: Grid<bool>,
+}
+
+impl Map {
+    fn from(tiles: &Grid<char>) -> Self {
+        Map {
+            obstacles: tiles.map(|&tile| tile == '#'),
+        }
+    }
+}
+
+fn parse_tiles(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |_, tile| match tile {
+        '.' | '#' | '^' | '>' | 'v' | '<' => Ok(tile),
+        _ => Err(format!("unexpected `{tile}`, expected `.`, `#` or a guard")),
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub enum GuardStatus {
+    Moved,
+    InfiniteLoop,
+    Exited,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Guard {
+    pub(crate) map: Map,
+    pub(crate) position: Point,
+    pub(crate) direction: Direction,
+    /// Every tile the guard has stood on, and which way they were facing.
+    pub(crate) visited: HashSet<(Point, Direction)>,
+}
+
+impl Guard {
+    pub fn init(input: &str) -> Result<Self, ParseError> {
+        let tiles = parse_tiles(input)?;
+        let (position, direction) = tiles
+            .iter()
+            .find_map(|(point, &tile)| Direction::from_arrow(tile).map(|dir| (point, dir)))
+            .ok_or_else(|| ParseError::eof(input, "a guard (`^`, `>`, `v` or `<`)"))?;
+
+        Ok(Guard {
+            map: Map::from(&tiles),
+            position,
+            direction,
+            visited: HashSet::from([(position, direction)]),
+        })
+    }
+
+    fn check_next_step(&self) -> Position {
+        match self.map.obstacles.get(self.position.step(self.direction)) {
+            None => Position::Exit,
+            Some(true) => Position::Obstacle,
+            Some(false) => Position::Open,
+        }
+    }
+
+    pub fn step(&mut self) -> GuardStatus {
+        match self.check_next_step() {
+            Position::Open => {
+                self.position = self.position.step(self.direction);
+
+                // Note that if the guard reaches the same position with the same
+                // direction, the guard is in an inifite loop.
+                if !self.visited.insert((self.position, self.direction)) {
+                    return GuardStatus::InfiniteLoop;
+                }
+            }
+            Position::Obstacle => {
+                self.direction = self.direction.turn_right();
+                self.step();
+            }
+            Position::Exit => {
+                // Guard has exited
+                return GuardStatus::Exited;
+            }
+        }
+
+        // Guard is still present
+        GuardStatus::Moved
+    }
+
+    /// Every tile the guard has stood on, whichever way they were facing.
+    pub fn tiles(&self) -> HashSet<Point> {
+        self.visited.iter().map(|&(point, _)| point).collect()
+    }
+
+    /// Draws the lab with the guard and every tile they have visited.
+    pub fn frame(&self) -> Frame {
+        let tiles = self.tiles();
+
+        self.map
+            .obstacles
+            .frame(|point, &obstacle| {
+                if point == self.position {
+                    Cell::new(self.direction.arrow(), Colour::Yellow)
+                } else if obstacle {
+                    Cell::plain('#')
+                } else if tiles.contains(&point) {
+                    Cell::new('X', Colour::Cyan)
+                } else {
+                    Cell::new('.', Colour::Grey)
+                }
+            })
+            .with_caption(format!("{} tiles visited", tiles.len()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Guard, ParseError> {
+    Guard::init(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_map() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."
+            .to_string();
+
+        let map = Map::from(&parse_tiles(&input).unwrap());
+
+        assert_eq!(map.obstacles.width(), 10);
+        assert_eq!(map.obstacles.height(), 10);
+        assert_eq!(
+            map.obstacles.find_all(&true).collect::<Vec<_>>(),
+            vec![
+                Point::new(4, 0),
+                Point::new(9, 1),
+                Point::new(2, 3),
+                Point::new(7, 4),
+                Point::new(1, 6),
+                Point::new(8, 7),
+                Point::new(0, 8),
+                Point::new(6, 9)
+            ],
+        );
+    }
+
+    #[test]
+    fn it_finds_guards_initial_position() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."
+            .to_string();
+
+        let map = Map::from(&parse_tiles(&input).unwrap());
+
+        assert_eq!(
+            Guard::init(&input).unwrap(),
+            Guard {
+                map,
+                position: Point::new(4, 6),
+                direction: Direction::Up,
+                visited: HashSet::from([(Point::new(4, 6), Direction::Up)]),
+            }
+        );
+    }
+
+    #[test]
+    fn direction_rotates() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_right(), Direction::Down);
+        assert_eq!(Direction::Down.turn_right(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    }
+
+    #[test]
+    fn it_check_next_step() {
+        let input = "....
+<..."
+            .to_string();
+
+        let guard = Guard::init(&input).unwrap();
+        assert_eq!(guard.check_next_step(), Position::Exit);
+
+        let input = "#...
+^..."
+            .to_string();
+
+        let guard = Guard::init(&input).unwrap();
+        assert_eq!(guard.check_next_step(), Position::Obstacle);
+
+        let input = "....
+^..."
+            .to_string();
+
+        let guard = Guard::init(&input).unwrap();
+        assert_eq!(guard.check_next_step(), Position::Open);
+    }
+
+    #[test]
+    fn it_moves_up() {
+        let input = "....
+^..."
+            .to_string();
+
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(0, 0),
+                direction: Direction::Up,
+                visited: HashSet::from([
+                    (Point::new(0, 1), Direction::Up),
+                    (Point::new(0, 0), Direction::Up),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_moves_down() {
+        let input = "v...
+...."
+            .to_string();
+
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(0, 1),
+                direction: Direction::Down,
+                visited: HashSet::from([
+                    (Point::new(0, 1), Direction::Down),
+                    (Point::new(0, 0), Direction::Down)
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_moves_right() {
+        let input = ">...
+...."
+            .to_string();
+
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(1, 0),
+                direction: Direction::Right,
+                visited: HashSet::from([
+                    (Point::new(0, 0), Direction::Right),
+                    (Point::new(1, 0), Direction::Right)
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_moves_left() {
+        let input = ".<..
+...."
+            .to_string();
+
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(0, 0),
+                direction: Direction::Left,
+                visited: HashSet::from([
+                    (Point::new(0, 0), Direction::Left),
+                    (Point::new(1, 0), Direction::Left)
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_turns_at_an_obstacle() {
+        let input = ".>#.
+...."
+            .to_string();
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(1, 1),
+                direction: Direction::Down,
+                visited: HashSet::from([
+                    (Point::new(1, 0), Direction::Right),
+                    (Point::new(1, 1), Direction::Down)
+                ]),
+            }
+        );
+
+        let input = "..#.
+..^."
+            .to_string();
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(3, 1),
+                direction: Direction::Right,
+                visited: HashSet::from([
+                    (Point::new(2, 1), Direction::Up),
+                    (Point::new(3, 1), Direction::Right)
+                ]),
+            }
+        );
+
+        let input = "....
+.#<."
+            .to_string();
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(2, 0),
+                direction: Direction::Up,
+                visited: HashSet::from([
+                    (Point::new(2, 0), Direction::Up),
+                    (Point::new(2, 1), Direction::Left)
+                ]),
+            }
+        );
+
+        let input = "..v.
+..#."
+            .to_string();
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(1, 0),
+                direction: Direction::Left,
+                visited: HashSet::from([
+                    (Point::new(2, 0), Direction::Down),
+                    (Point::new(1, 0), Direction::Left)
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_handles_multiple_obstacles() {
+        let input = "..>#
+..#."
+            .to_string();
+        let map = Map::from(&parse_tiles(&input).unwrap());
+        let mut guard = Guard::init(&input).unwrap();
+        guard.step();
+
+        assert_eq!(
+            guard,
+            Guard {
+                map,
+                position: Point::new(1, 0),
+                direction: Direction::Left,
+                visited: HashSet::from([
+                    (Point::new(2, 0), Direction::Right),
+                    (Point::new(1, 0), Direction::Left)
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_exits() {
+        let input = "<...
+...."
+            .to_string();
+        let mut guard = Guard::init(&input).unwrap();
+
+        assert_eq!(guard.step(), GuardStatus::Exited);
+
+        let input = "....
+...>"
+            .to_string();
+        let mut guard = Guard::init(&input).unwrap();
+
+        assert_eq!(guard.step(), GuardStatus::Exited);
+
+        let input = "....
+..v."
+            .to_string();
+        let mut guard = Guard::init(&input).unwrap();
+
+        assert_eq!(guard.step(), GuardStatus::Exited);
+
+        let input = "...^
+...."
+            .to_string();
+        let mut guard = Guard::init(&input).unwrap();
+
+        assert_eq!(guard.step(), GuardStatus::Exited);
+    }
+
+    #[test]
+    fn check_inifinite_loop() {
+        let input = ".#...
+#^..#
+...#."
+            .to_string();
+        let mut guard = Guard::init(&input).unwrap();
+        let mut inf_loop = false;
+
+        while !inf_loop {
+            let result = guard.step();
+
+            if result == GuardStatus::InfiniteLoop {
+                inf_loop = true;
+            }
+        }
+
+        assert!(inf_loop);
+    }
+
+    #[test]
+    fn it_rejects_bad_maps() {
+        assert_eq!(
+            Guard::init("..#\n.x^").unwrap_err().to_string(),
+            "2:2: unexpected `x`, expected `.`, `#` or a guard"
+        );
+        assert_eq!(
+            Guard::init("..#\n...").unwrap_err().to_string(),
+            "2:4: expected a guard (`^`, `>`, `v` or `<`), found end of input"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use common::{Answer, ParseError, Solution};
+use grid::Frames;
+
+pub mod lab;
+pub mod loops;
+pub mod part1;
+pub mod part2;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input<'a> = lab::Guard;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lab::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
+
+/// Draws each step of the guard's patrol for `aoc run --visualize`, or for
+/// part 2 the loop each obstacle traps them in.
+pub fn frames(part: u8, input: &str) -> Option<Result<Frames, ParseError>> {
+    match part {
+        1 => Some(lab::parse(input).map(|guard| part1::frames(&guard))),
+        2 => Some(lab::parse(input).map(|guard| loops::frames(&guard))),
+        _ => None,
+    }
+}
@@ -0,0 +1,266 @@
+use crate::lab::Guard;
+use grid::{Cell, Colour, Direction, Frame, Frames, Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+/// Somewhere off the map, for walking without an extra obstacle.
+const NOWHERE: Point = Point::new(-1, -1);
+
+/// A new obstacle that traps the guard, and how many steps they take to go
+/// round the loop it causes once.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LoopObstacle {
+    pub position: Point,
+    pub cycle: usize,
+}
+
+/// Walks from `position` with an extra obstacle at `extra`, calling `visit`
+/// on each tile, until the guard leaves the lab or comes back to a turn they
+/// have already made. Returns the number of steps between the two turns.
+///
+/// Loops are only checked at turns, since a guard can't go round one without
+/// turning.
+fn walk(
+    obstacles: &Grid<bool>,
+    extra: Point,
+    mut position: Point,
+    mut direction: Direction,
+    mut visit: impl FnMut(Point),
+) -> Option<usize> {
+    let mut turns = HashMap::new();
+    let mut steps = 0;
+
+    loop {
+        let next = position.step(direction);
+        match obstacles.get(next) {
+            None => return None,
+            Some(&obstacle) if obstacle || next == extra => {
+                if let Some(before) = turns.insert((position, direction), steps) {
+                    return Some(steps - before);
+                }
+                direction = direction.turn_right();
+            }
+            Some(_) => {
+                position = next;
+                steps += 1;
+                visit(position);
+            }
+        }
+    }
+}
+
+/// Every open tile where one more obstacle would trap the guard in a loop,
+/// in reading order.
+///
+/// An obstacle the guard never walks into changes nothing, so only the tiles
+/// on their original path are tried. Each one is tried from where the guard
+/// is standing the first time they are about to step onto it, which the path
+/// up to then is the same with or without it.
+pub fn loop_obstacles(guard: &Guard) -> Vec<LoopObstacle> {
+    let obstacles = &guard.map.obstacles;
+    let (mut position, mut direction) = (guard.position, guard.direction);
+    let mut tried = HashSet::from([position]);
+    let mut turns = HashSet::new();
+    let mut found = Vec::new();
+
+    loop {
+        let next = position.step(direction);
+        match obstacles.get(next) {
+            None => break,
+            Some(true) => {
+                // The guard's own patrol is already a loop
+                if !turns.insert((position, direction)) {
+                    break;
+                }
+                direction = direction.turn_right();
+            }
+            Some(false) => {
+                if tried.insert(next) {
+                    if let Some(cycle) = walk(obstacles, next, position, direction, |_| ()) {
+                        found.push(LoopObstacle {
+                            position: next,
+                            cycle,
+                        });
+                    }
+                }
+                position = next;
+            }
+        }
+    }
+
+    found.sort_by_key(|obstacle| (obstacle.position.y, obstacle.position.x));
+    found
+}
+
+/// One line for each loop obstacle, and how many there are.
+pub fn report(found: &[LoopObstacle]) -> String {
+    let mut lines = vec![format!(
+        "{} obstacle positions trap the guard in a loop",
+        found.len()
+    )];
+    lines.extend(found.iter().map(|obstacle| {
+        format!(
+            "{:>9}  loop of {} steps",
+            obstacle.position.to_string(),
+            obstacle.cycle
+        )
+    }));
+
+    lines.join("\n")
+}
+
+/// The lab with the guard where they start, their original path, and an `O`
+/// on every tile where an obstacle would trap them.
+pub fn frame(guard: &Guard, found: &[LoopObstacle]) -> Frame {
+    let positions = found
+        .iter()
+        .map(|obstacle| obstacle.position)
+        .collect::<HashSet<_>>();
+    let mut path = HashSet::new();
+    walk(
+        &guard.map.obstacles,
+        NOWHERE,
+        guard.position,
+        guard.direction,
+        |point| {
+            path.insert(point);
+        },
+    );
+
+    guard
+        .map
+        .obstacles
+        .frame(|point, &obstacle| {
+            if point == guard.position {
+                Cell::new(guard.direction.arrow(), Colour::Yellow)
+            } else if obstacle {
+                Cell::plain('#')
+            } else if positions.contains(&point) {
+                Cell::new('O', Colour::Red)
+            } else if path.contains(&point) {
+                Cell::new('X', Colour::Cyan)
+            } else {
+                Cell::new('.', Colour::Grey)
+            }
+        })
+        .with_caption(format!("{} loop obstacles", found.len()))
+}
+
+/// A frame for each loop obstacle, showing the loop it traps the guard in.
+pub fn frames(guard: &Guard) -> Frames {
+    let guard = guard.clone();
+    let found = loop_obstacles(&guard);
+    let total = found.len();
+
+    Box::new(found.into_iter().enumerate().map(move |(i, found)| {
+        let mut tiles = HashSet::new();
+        walk(
+            &guard.map.obstacles,
+            found.position,
+            guard.position,
+            guard.direction,
+            |point| {
+                tiles.insert(point);
+            },
+        );
+
+        guard
+            .map
+            .obstacles
+            .frame(|point, &obstacle| {
+                if point == found.position {
+                    Cell::new('O', Colour::Red)
+                } else if point == guard.position {
+                    Cell::new(guard.direction.arrow(), Colour::Yellow)
+                } else if obstacle {
+                    Cell::plain('#')
+                } else if tiles.contains(&point) {
+                    Cell::new('X', Colour::Magenta)
+                } else {
+                    Cell::new('.', Colour::Grey)
+                }
+            })
+            .with_caption(format!(
+                "obstacle {} of {total} at {}: loop of {} steps",
+                i + 1,
+                found.position,
+                found.cycle
+            ))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAB: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn it_finds_loop_obstacles() {
+        let guard = Guard::init(LAB).unwrap();
+        let found = loop_obstacles(&guard);
+
+        assert_eq!(
+            found
+                .iter()
+                .map(|obstacle| obstacle.position)
+                .collect::<Vec<_>>(),
+            vec![
+                Point::new(3, 6),
+                Point::new(6, 7),
+                Point::new(7, 7),
+                Point::new(1, 8),
+                Point::new(3, 8),
+                Point::new(7, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_cycle_lengths() {
+        let guard = Guard::init(LAB).unwrap();
+        let found = loop_obstacles(&guard);
+
+        assert_eq!(found[0].cycle, 18);
+        assert!(report(&found).starts_with(
+            "6 obstacle positions trap the guard in a loop\n      3,6  loop of 18 steps\n"
+        ));
+    }
+
+    #[test]
+    fn it_draws_the_obstacles() {
+        let guard = Guard::init(LAB).unwrap();
+        let found = loop_obstacles(&guard);
+
+        assert_eq!(
+            frame(&guard, &found).to_string(),
+            "....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XO^XXXX.
+.XXXXXOO#.
+#OXOXXXX..
+......#O..
+6 loop obstacles"
+        );
+        assert_eq!(frames(&guard).count(), 6);
+    }
+
+    #[test]
+    fn it_gives_up_on_a_patrol_that_already_loops() {
+        let guard = Guard::init(".#..\n...#\n#^..\n..#.").unwrap();
+
+        assert_eq!(loop_obstacles(&guard), vec![]);
+    }
+}
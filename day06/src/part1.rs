@@ -0,0 +1,59 @@
+use crate::lab::{Guard, GuardStatus};
+use common::Answer;
+use grid::Frames;
+use std::iter;
+
+pub fn solve(guard: &Guard) -> Answer {
+    let mut guard = guard.clone();
+
+    while guard.step() == GuardStatus::Moved {}
+
+    guard.tiles().len().into()
+}
+
+/// Every step of the guard's patrol, until they walk out of the lab.
+pub fn frames(guard: &Guard) -> Frames {
+    let mut guard = guard.clone();
+    let first = guard.frame();
+
+    Box::new(iter::once(first).chain(iter::from_fn(move || {
+        (guard.step() == GuardStatus::Moved).then(|| guard.frame())
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_counts_visited_tiles() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+        assert_eq!(solve(&Guard::init(input).unwrap()), Answer::Int(41));
+    }
+
+    #[test]
+    fn it_draws_the_patrol() {
+        let input = "#...
+...#
+^...";
+        let guard = Guard::init(input).unwrap();
+        let frames = frames(&guard).collect::<Vec<_>>();
+
+        assert_eq!(frames[0].to_string(), "#...\n...#\n^...\n1 tiles visited");
+        assert_eq!(
+            frames.last().unwrap().to_string(),
+            "#...\nXXX#\nX.v.\n5 tiles visited"
+        );
+        assert_eq!(frames.len(), 5);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{lab::Guard, loops};
+use common::Answer;
+
+pub fn solve(guard: &Guard) -> Answer {
+    loops::loop_obstacles(guard).len().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_counts_loop_obstacles() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+        assert_eq!(solve(&Guard::init(input).unwrap()), Answer::Int(6));
+    }
+}
@@ -0,0 +1,195 @@
+use common::{parse, ParseError};
+use itertools::Itertools;
+
+/// The operators that can be placed between an equation's operands.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Operators {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operators {
+    /// `acc` and `n` combined, or `None` if the result doesn't fit in a `u64`.
+    fn apply(&self, acc: u64, n: u64) -> Option<u64> {
+        match self {
+            Operators::Add => acc.checked_add(n),
+            Operators::Multiply => acc.checked_mul(n),
+            Operators::Concat => {
+                let digits = n.checked_ilog10().map_or(1, |log| log + 1);
+                acc.checked_mul(10u64.checked_pow(digits)?)?.checked_add(n)
+            }
+        }
+    }
+}
+
+/// A test value and the operands that might combine to produce it.
+#[derive(Debug, PartialEq)]
+pub struct Equation {
+    result: u64,
+    operands: Vec<u64>,
+}
+
+impl Equation {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let (result, operands) = parse::split_once(input, input, ":")?;
+
+        let result = parse::number(input, result.trim())?;
+        let operands = operands
+            .split_whitespace()
+            .map(|n| parse::number(input, n))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if operands.is_empty() {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                "expected at least one operand",
+            ));
+        }
+
+        Ok(Self { result, operands })
+    }
+
+    /// The test value, if some combination of `operators` produces it. A
+    /// combination that overflows a `u64` on the way doesn't.
+    pub fn evaluate(&self, operators: &[Operators]) -> Option<u64> {
+        let (&first, rest) = self.operands.split_first()?;
+
+        (0..rest.len())
+            .map(|_| operators.to_vec())
+            .multi_cartesian_product()
+            .any(|test| {
+                let result = rest
+                    .iter()
+                    .zip(test.iter())
+                    .try_fold(first, |acc, (&n, op)| op.apply(acc, n));
+                result == Some(self.result)
+            })
+            .then_some(self.result)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(n, line)| Equation::from(line).map_err(|err| err.on_line(n + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADD_MULTIPLY: [Operators; 2] = [Operators::Add, Operators::Multiply];
+
+    #[test]
+    fn it_parses_a_line() {
+        let input = "190: 10 19".to_string();
+
+        assert_eq!(
+            Equation::from(&input).unwrap(),
+            Equation {
+                result: 190,
+                operands: vec![10, 19],
+            }
+        );
+    }
+
+    #[test]
+    fn it_evaluates_valid() {
+        let input = "190: 10 19".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), Some(190));
+
+        let input = "3267: 81 40 27".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), Some(3267));
+
+        let input = "292: 11 6 16 20".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), Some(292));
+    }
+
+    #[test]
+    fn it_evaluates_invalid() {
+        let input = "83: 17 5".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), None);
+
+        let input = "156: 15 6".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), None);
+
+        let input = "7290: 6 8 6 15".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), None);
+
+        let input = "161011: 16 10 13".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), None);
+
+        let input = "192: 17 8 14".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), None);
+
+        let input = "21037: 9 7 18 13".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&ADD_MULTIPLY), None);
+    }
+
+    #[test]
+    fn it_evaluates_concatenation() {
+        let all = [Operators::Add, Operators::Multiply, Operators::Concat];
+
+        let input = "156: 15 6".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), Some(156));
+
+        let input = "7290: 6 8 6 15".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), Some(7290));
+
+        let input = "161011: 16 10 13".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), None);
+    }
+
+    #[test]
+    fn it_skips_combinations_that_overflow() {
+        let all = [Operators::Add, Operators::Multiply, Operators::Concat];
+
+        let input = format!("{}: {} 2 1", u64::MAX, u64::MAX);
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), None);
+
+        let input = format!("{}: {} 1 1", u64::MAX, u64::MAX);
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), Some(u64::MAX));
+
+        let input = format!("{}: 1844674407370955161 5", u64::MAX);
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), Some(u64::MAX));
+
+        // Concatenating a 20-digit number overflows, but adding doesn't
+        let input = "10000000000000000001: 1 10000000000000000000".to_string();
+        let eq = Equation::from(&input).unwrap();
+        assert_eq!(eq.evaluate(&all), Some(10_000_000_000_000_000_001));
+    }
+
+    #[test]
+    fn it_rejects_bad_equations() {
+        let error = |input| parse(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("190: 10 19\n3267 81 40 27"),
+            "2:1: expected `:` in `3267 81 40 27`"
+        );
+        assert_eq!(
+            error("190: 10 19\n83: 17 five"),
+            "2:8: expected a number, found `five`"
+        );
+        assert_eq!(error("190:"), "1:5: expected at least one operand");
+    }
+}
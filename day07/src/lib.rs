@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod equation;
+pub mod part1;
+pub mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input<'a> = Vec<equation::Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        equation::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::equation::{Equation, Operators};
+use common::Answer;
+
+pub fn solve(equations: &[Equation]) -> Answer {
+    equations
+        .iter()
+        .filter_map(|equation| equation.evaluate(&[Operators::Add, Operators::Multiply]))
+        .sum::<u64>()
+        .into()
+}
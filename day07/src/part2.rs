@@ -0,0 +1,13 @@
+use crate::equation::{Equation, Operators};
+use common::Answer;
+use rayon::prelude::*;
+
+pub fn solve(equations: &[Equation]) -> Answer {
+    let operators = [Operators::Add, Operators::Multiply, Operators::Concat];
+
+    equations
+        .par_iter()
+        .filter_map(|equation| equation.evaluate(&operators))
+        .sum::<u64>()
+        .into()
+}
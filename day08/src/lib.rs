@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod map;
+pub mod part1;
+pub mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input<'a> = map::Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        map::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,227 @@
+use common::ParseError;
+use grid::{Grid, Point};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// The roof and where each frequency's antennas are on it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Map {
+    tiles: Grid<char>,
+    antennas: HashMap<char, Vec<Point>>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, |_, ch| {
+            if ch.is_ascii_alphanumeric() || ch == '.' {
+                Ok(ch)
+            } else {
+                Err(format!("unexpected `{ch}`, expected `.` or an antenna"))
+            }
+        })?;
+
+        let mut antennas = HashMap::new();
+        for (point, &ch) in tiles.iter().filter(|(_, &ch)| ch != '.') {
+            antennas.entry(ch).or_insert_with(Vec::new).push(point);
+        }
+
+        Ok(Self { tiles, antennas })
+    }
+
+    /// Every point on the map in line with two antennas of the same
+    /// frequency, either just beyond each of them or, with `harmonics`, at
+    /// every multiple of the distance between them.
+    pub fn find_antinodes(&self, harmonics: bool) -> HashSet<Point> {
+        let mut antinodes = HashSet::new();
+
+        self.antennas.iter().for_each(|antenna| {
+            let pairs: Vec<_> = antenna.1.iter().combinations(2).collect();
+            pairs.iter().for_each(|coords| {
+                let diff_vec = *coords[1] - *coords[0];
+
+                if harmonics {
+                    let mut antinode = *coords[0];
+                    while self.tiles.contains(antinode) {
+                        antinodes.insert(antinode);
+                        antinode -= diff_vec;
+                    }
+
+                    let mut antinode = *coords[1];
+                    while self.tiles.contains(antinode) {
+                        antinodes.insert(antinode);
+                        antinode += diff_vec;
+                    }
+                } else {
+                    let antinode_1 = *coords[0] - diff_vec;
+                    let antinode_2 = *coords[1] + diff_vec;
+
+                    if self.tiles.contains(antinode_1) {
+                        antinodes.insert(antinode_1);
+                    }
+
+                    if self.tiles.contains(antinode_2) {
+                        antinodes.insert(antinode_2);
+                    }
+                }
+            });
+        });
+
+        antinodes
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_map() {
+        let input = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"
+            .to_string();
+
+        assert_eq!(
+            Map::parse(&input).unwrap(),
+            Map {
+                tiles: Grid::parse_chars(&input).unwrap(),
+                antennas: HashMap::from([
+                    (
+                        '0',
+                        vec![
+                            Point::new(8, 1),
+                            Point::new(5, 2),
+                            Point::new(7, 3),
+                            Point::new(4, 4)
+                        ]
+                    ),
+                    (
+                        'A',
+                        vec![Point::new(6, 5), Point::new(8, 8), Point::new(9, 9)]
+                    ),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_calculates_antinodes_in_bounds() {
+        let input = "..........
+..........
+..........
+....a.....
+..........
+.....a....
+..........
+..........
+..........
+.........."
+            .to_string();
+        let map = Map::parse(&input).unwrap();
+
+        assert_eq!(
+            map.find_antinodes(false),
+            HashSet::from([Point::new(3, 1), Point::new(6, 7)])
+        );
+    }
+
+    #[test]
+    fn it_calculates_antinodes_out_of_bounds() {
+        let input = "....a.....
+..........
+.....a....
+..........
+..........
+..........
+..........
+..........
+..........
+.........."
+            .to_string();
+        let map = Map::parse(&input).unwrap();
+
+        assert_eq!(map.find_antinodes(false), HashSet::from([Point::new(6, 4)]));
+    }
+
+    #[test]
+    fn it_runs_the_test_input() {
+        let input = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"
+            .to_string();
+        let map = Map::parse(&input).unwrap();
+
+        assert_eq!(
+            map.find_antinodes(false),
+            HashSet::from([
+                Point::new(6, 0),
+                Point::new(11, 0),
+                Point::new(3, 1),
+                Point::new(4, 2),
+                Point::new(10, 2),
+                Point::new(2, 3),
+                Point::new(9, 4),
+                Point::new(1, 5),
+                Point::new(6, 5),
+                Point::new(3, 6),
+                Point::new(0, 7),
+                Point::new(7, 7),
+                Point::new(10, 10),
+                Point::new(10, 11),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_calculates_harmonics() {
+        let input = "T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+.........."
+            .to_string();
+        let map = Map::parse(&input).unwrap();
+
+        assert_eq!(
+            map.find_antinodes(true),
+            HashSet::from([
+                Point::new(0, 0),
+                Point::new(3, 1),
+                Point::new(1, 2),
+                Point::new(5, 0),
+                Point::new(6, 2),
+                Point::new(9, 3),
+                Point::new(2, 4),
+                Point::new(3, 6),
+                Point::new(4, 8),
+            ])
+        );
+    }
+}
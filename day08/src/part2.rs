@@ -0,0 +1,6 @@
+use crate::map::Map;
+use common::Answer;
+
+pub fn solve(map: &Map) -> Answer {
+    map.find_antinodes(true).len().into()
+}
@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod disk;
+pub mod part1;
+pub mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input<'a> = disk::DiskMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        disk::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,6 @@
+use crate::disk::DiskMap;
+use common::Answer;
+
+pub fn solve(disk_map: &DiskMap) -> Answer {
+    disk_map.clone().compress_blocks().into()
+}
@@ -0,0 +1,230 @@
+use common::ParseError;
+use grid::{Direction, Grid, Point};
+use std::collections::HashMap;
+
+/// The topographic map, with the points at each elevation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Map {
+    heights: Grid<u32>,
+    pub(crate) elevations: HashMap<u32, Vec<Point>>,
+}
+
+impl Map {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let heights = Grid::parse(input, |_, ch| {
+            ch.to_digit(10)
+                .ok_or_else(|| format!("expected an elevation, found `{ch}`"))
+        })?;
+
+        let mut elevations = HashMap::new();
+        for (point, &elevation) in heights.iter() {
+            elevations
+                .entry(elevation)
+                .or_insert_with(Vec::new)
+                .push(point);
+        }
+
+        Ok(Map {
+            heights,
+            elevations,
+        })
+    }
+}
+
+/// Every track from one trailhead up to one summit.
+#[derive(Debug)]
+pub(crate) struct Trail {
+    map: Map,
+    start: Point,
+    end: Point,
+    tracks: Vec<Vec<Point>>,
+}
+
+impl PartialEq for Trail {
+    fn eq(&self, other: &Self) -> bool {
+        (self.start == other.start) && (self.end == other.end)
+    }
+}
+
+impl Trail {
+    pub(crate) fn from(start: &Point, end: &Point, map: &Map) -> Self {
+        Trail {
+            map: map.clone(),
+            start: *start,
+            end: *end,
+            tracks: Vec::new(),
+        }
+    }
+
+    /// Finds every track from the start to the end, returning how many
+    /// there are.
+    pub(crate) fn find_tracks(&mut self) -> u32 {
+        let successors = |point: &Point| {
+            let elevation = self.map.heights[*point];
+            let next = self.get_next(point, elevation).unwrap_or_default();
+            next.into_iter().map(|next| (next, 1u32))
+        };
+
+        // Every step climbs by one, so every track to the summit is a
+        // shortest path.
+        let mut tracks = search::dijkstra_all(&self.start, successors, |&p| p == self.end)
+            .map(|(paths, _)| paths.paths())
+            .unwrap_or_default();
+        tracks.sort();
+
+        self.tracks = tracks;
+        self.tracks.len() as u32
+    }
+
+    fn get_next(&self, coord: &Point, elevation: u32) -> Option<Vec<Point>> {
+        if elevation >= 9 {
+            return None;
+        }
+
+        // Look up, left, right and then down
+        let next = [
+            Direction::Up,
+            Direction::Left,
+            Direction::Right,
+            Direction::Down,
+        ]
+        .into_iter()
+        .map(|direction| coord.step(direction))
+        .filter(|&check| self.map.heights.get(check) == Some(&(elevation + 1)))
+        .collect::<Vec<_>>();
+
+        if next.is_empty() {
+            return None;
+        }
+
+        Some(next)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::from(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_map() {
+        let input = "0123
+1234
+8765
+9876"
+            .to_string();
+        let map = Map::from(&input).unwrap();
+
+        assert_eq!((map.heights.width(), map.heights.height()), (4, 4));
+        assert_eq!(
+            map.elevations,
+            HashMap::from([
+                (0, vec![Point::new(0, 0)]),
+                (1, vec![Point::new(1, 0), Point::new(0, 1)]),
+                (2, vec![Point::new(2, 0), Point::new(1, 1)]),
+                (3, vec![Point::new(3, 0), Point::new(2, 1)]),
+                (4, vec![Point::new(3, 1)]),
+                (5, vec![Point::new(3, 2)]),
+                (6, vec![Point::new(2, 2), Point::new(3, 3)]),
+                (7, vec![Point::new(1, 2), Point::new(2, 3)]),
+                (8, vec![Point::new(0, 2), Point::new(1, 3)]),
+                (9, vec![Point::new(0, 3)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_creates_a_trail() {
+        let input = "0123
+8834
+8885
+9876"
+            .to_string();
+        let map = Map::from(&input).unwrap();
+        let trail = Trail::from(
+            &map.elevations.get(&0).unwrap()[0],
+            &map.elevations.get(&9).unwrap()[0],
+            &map,
+        );
+
+        assert_eq!(
+            trail,
+            Trail {
+                map,
+                start: Point::new(0, 0),
+                end: Point::new(0, 3),
+                tracks: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_gets_next_tracks() {
+        let input = "0123
+1134
+8885
+9876"
+            .to_string();
+        let map = Map::from(&input).unwrap();
+        let trail = Trail::from(
+            &map.elevations.get(&0).unwrap()[0],
+            &map.elevations.get(&9).unwrap()[0],
+            &map,
+        );
+
+        assert_eq!(
+            trail.get_next(&trail.start, 0),
+            Some(vec![Point::new(1, 0), Point::new(0, 1),])
+        );
+    }
+
+    #[test]
+    fn it_finds_trails() {
+        let input = "0123
+8834
+1115
+9876"
+            .to_string();
+        let map = Map::from(&input).unwrap();
+        let mut trail = Trail::from(
+            &map.elevations.get(&0).unwrap()[0],
+            &map.elevations.get(&9).unwrap()[0],
+            &map,
+        );
+        let result = trail.find_tracks();
+
+        assert_eq!(result, 2);
+        assert_eq!(
+            trail.tracks,
+            vec![
+                vec![
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(2, 0),
+                    Point::new(2, 1),
+                    Point::new(3, 1),
+                    Point::new(3, 2),
+                    Point::new(3, 3),
+                    Point::new(2, 3),
+                    Point::new(1, 3),
+                    Point::new(0, 3),
+                ],
+                vec![
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(2, 0),
+                    Point::new(3, 0),
+                    Point::new(3, 1),
+                    Point::new(3, 2),
+                    Point::new(3, 3),
+                    Point::new(2, 3),
+                    Point::new(1, 3),
+                    Point::new(0, 3),
+                ],
+            ]
+        );
+    }
+}
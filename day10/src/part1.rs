@@ -0,0 +1,36 @@
+use crate::map::{Map, Trail};
+use common::Answer;
+use itertools::Itertools;
+
+pub fn solve(map: &Map) -> Answer {
+    let trailheads = map.elevations.get(&0).map_or(&[][..], Vec::as_slice);
+    let summits = map.elevations.get(&9).map_or(&[][..], Vec::as_slice);
+
+    let mut score = 0;
+
+    trailheads
+        .iter()
+        .cartesian_product(summits.iter())
+        .for_each(|coords| {
+            let mut trail = Trail::from(coords.0, coords.1, map);
+            let num_tracks = trail.find_tracks();
+
+            if num_tracks > 0 {
+                score += 1;
+            }
+        });
+
+    score.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map;
+
+    #[test]
+    fn it_scores_maps_without_trailheads_or_summits() {
+        assert_eq!(solve(&map::parse("0123\n7654").unwrap()), Answer::Int(0));
+        assert_eq!(solve(&map::parse("9876\n1234").unwrap()), Answer::Int(0));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::map::{Map, Trail};
+use common::Answer;
+use itertools::Itertools;
+
+pub fn solve(map: &Map) -> Answer {
+    let trailheads = map.elevations.get(&0).map_or(&[][..], Vec::as_slice);
+    let summits = map.elevations.get(&9).map_or(&[][..], Vec::as_slice);
+
+    let mut rating = 0;
+
+    trailheads
+        .iter()
+        .cartesian_product(summits.iter())
+        .for_each(|coords| {
+            let mut trail = Trail::from(coords.0, coords.1, map);
+            let num_tracks = trail.find_tracks();
+            rating += num_tracks;
+        });
+
+    rating.into()
+}
@@ -0,0 +1,47 @@
+// Blinks and prints how many stones there are after each blink, how many
+// different numbers are engraved on them, and the largest one.
+// `--rules <file>` reads the rules from a file instead of using the
+// puzzle's, and `--blinks <n>` sets how many times to blink (75 by default).
+// To run: `cargo run --bin blinks -- src/bin/input.txt --blinks 1000`
+
+use common::{args, Solution};
+use day11::{rules, Day11};
+use std::{env, fs};
+
+const USAGE: &str = "blinks [input] [--rules <file>] [--blinks <n>]";
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let rules_path = args::value(&args, "--rules").unwrap_or_else(|err| args::usage(&err, USAGE));
+    let blinks = args::number::<usize>(&args, "--blinks")
+        .unwrap_or_else(|err| args::usage(&err, USAGE))
+        .unwrap_or(75);
+
+    let input = fs::read_to_string(&path).expect("to read file");
+    let mut stones = Day11::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+    let rules = match rules_path {
+        Some(path) => {
+            let input = fs::read_to_string(path).expect("to read rules");
+            rules::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"))
+        }
+        None => rules::Rules::default(),
+    };
+
+    // Print each step as it's reached, even if a later one overflows
+    for _ in 0..blinks {
+        match stones.blink_with(&rules, 1) {
+            Ok(steps) => {
+                let step = steps[0];
+                println!(
+                    "blink {}: {} stones, {} distinct, largest {}",
+                    step.blink, step.stones, step.distinct, step.largest
+                );
+            }
+            Err(err) => {
+                println!("{err}");
+                break;
+            }
+        }
+    }
+}
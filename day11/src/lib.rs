@@ -0,0 +1,25 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod rules;
+pub mod stones;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = stones::Stones;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        stones::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::stones::Stones;
+use common::Answer;
+
+pub fn solve(stones: &Stones) -> Answer {
+    match stones.clone().blink(75) {
+        Ok(count) => count.into(),
+        Err(err) => Answer::Error(err.to_string()),
+    }
+}
@@ -0,0 +1,282 @@
+use common::{parse, ParseError};
+use std::fmt;
+
+/// Which stones a rule applies to.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Condition {
+    Equals(u128),
+    EvenDigits,
+    OddDigits,
+    Any,
+}
+
+/// One stone a rule turns a stone `n` into.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Output {
+    Constant(u128),
+    Same,
+    Times(u128),
+    Plus(u128),
+    /// The first half of the digits of a stone with an even number of them.
+    Left,
+    /// The second half, without its leading zeros.
+    Right,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rule {
+    pub when: Condition,
+    pub then: Vec<Output>,
+}
+
+/// Rules tried in order on each stone, where the first that applies
+/// replaces it. A stone no rule applies to stays as it is.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rules(pub Vec<Rule>);
+
+/// The rules from the puzzle, in the format `parse` reads.
+pub const PUZZLE: &str = "n == 0 -> 1
+even digits -> left, right
+any -> n * 2024";
+
+/// The number of decimal digits in `n`.
+pub fn digits(n: u128) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+impl Condition {
+    fn matches(self, n: u128) -> bool {
+        match self {
+            Condition::Equals(value) => n == value,
+            Condition::EvenDigits => digits(n).is_multiple_of(2),
+            Condition::OddDigits => !digits(n).is_multiple_of(2),
+            Condition::Any => true,
+        }
+    }
+}
+
+impl Output {
+    /// The new stone, or `None` if it doesn't fit in a `u128`.
+    fn apply(self, n: u128) -> Option<u128> {
+        let half = || 10u128.pow(digits(n) / 2);
+
+        match self {
+            Output::Constant(value) => Some(value),
+            Output::Same => Some(n),
+            Output::Times(by) => n.checked_mul(by),
+            Output::Plus(by) => n.checked_add(by),
+            Output::Left => Some(n / half()),
+            Output::Right => Some(n % half()),
+        }
+    }
+}
+
+impl Rules {
+    /// What a stone engraved with `n` becomes after one blink, or `None` if
+    /// one of the new stones doesn't fit in a `u128`.
+    pub fn apply(&self, n: u128) -> Option<Vec<u128>> {
+        match self.0.iter().find(|rule| rule.when.matches(n)) {
+            Some(rule) => rule.then.iter().map(|output| output.apply(n)).collect(),
+            None => Some(vec![n]),
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        parse(PUZZLE).unwrap()
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Condition::Equals(value) => write!(f, "n == {value}"),
+            Condition::EvenDigits => write!(f, "even digits"),
+            Condition::OddDigits => write!(f, "odd digits"),
+            Condition::Any => write!(f, "any"),
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Output::Constant(value) => write!(f, "{value}"),
+            Output::Same => write!(f, "n"),
+            Output::Times(by) => write!(f, "n * {by}"),
+            Output::Plus(by) => write!(f, "n + {by}"),
+            Output::Left => write!(f, "left"),
+            Output::Right => write!(f, "right"),
+        }
+    }
+}
+
+impl fmt::Display for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines = self
+            .0
+            .iter()
+            .map(|rule| {
+                let outputs = rule.then.iter().map(Output::to_string);
+                format!(
+                    "{} -> {}",
+                    rule.when,
+                    outputs.collect::<Vec<_>>().join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+fn condition(input: &str, token: &str) -> Result<Condition, ParseError> {
+    let token = token.trim();
+
+    match token {
+        "even digits" => Ok(Condition::EvenDigits),
+        "odd digits" => Ok(Condition::OddDigits),
+        "any" => Ok(Condition::Any),
+        _ => match token.strip_prefix("n == ") {
+            Some(value) => Ok(Condition::Equals(parse::number(input, value.trim())?)),
+            None => Err(ParseError::at(
+                input,
+                token,
+                format!("expected a condition, found `{token}`"),
+            )),
+        },
+    }
+}
+
+fn output(input: &str, token: &str) -> Result<Output, ParseError> {
+    let token = token.trim();
+
+    match token {
+        "n" => Ok(Output::Same),
+        "left" => Ok(Output::Left),
+        "right" => Ok(Output::Right),
+        _ => {
+            if let Some(by) = token.strip_prefix("n * ") {
+                Ok(Output::Times(parse::number(input, by.trim())?))
+            } else if let Some(by) = token.strip_prefix("n + ") {
+                Ok(Output::Plus(parse::number(input, by.trim())?))
+            } else {
+                Ok(Output::Constant(parse::number(input, token).map_err(
+                    |_| {
+                        ParseError::at(input, token, format!("expected an output, found `{token}`"))
+                    },
+                )?))
+            }
+        }
+    }
+}
+
+/// Reads one rule per line as `condition -> output, output, ...`, skipping
+/// blank lines and `#` comments.
+///
+/// A condition is `n == <value>`, `even digits`, `odd digits` or `any`. An
+/// output is a number, `n`, `n * <value>`, `n + <value>`, or `left` or
+/// `right` for the halves of the digits. `left` and `right` only work under
+/// `even digits`, since they'd lose a digit otherwise.
+pub fn parse(input: &str) -> Result<Rules, ParseError> {
+    input
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (when, then) = parse::split_once(input, line, "->")?;
+            let when = condition(input, when)?;
+            let then = then
+                .split(',')
+                .map(|token| output(input, token))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if when != Condition::EvenDigits {
+                if let Some(half) = then
+                    .iter()
+                    .find(|output| matches!(output, Output::Left | Output::Right))
+                {
+                    return Err(ParseError::at(
+                        input,
+                        line.trim(),
+                        format!("`{half}` needs `even digits`, found `{when}`"),
+                    ));
+                }
+            }
+
+            Ok(Rule { when, then })
+        })
+        .collect::<Result<_, _>>()
+        .map(Rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_counts_digits() {
+        assert_eq!(
+            [0, 9, 10, 99, 100, 2024, u128::MAX].map(digits),
+            [1, 1, 2, 2, 3, 4, 39]
+        );
+    }
+
+    #[test]
+    fn it_applies_the_puzzle_rules() {
+        let rules = Rules::default();
+
+        assert_eq!(rules.apply(0), Some(vec![1]));
+        assert_eq!(rules.apply(1000), Some(vec![10, 0]));
+        assert_eq!(rules.apply(253000), Some(vec![253, 0]));
+        assert_eq!(rules.apply(999), Some(vec![2021976]));
+        assert_eq!(rules.apply(u128::MAX), None);
+        assert_eq!(rules.to_string(), PUZZLE);
+    }
+
+    #[test]
+    fn it_reads_rules() {
+        let rules = parse("# halve odd ones out\nn == 7 -> 7, n + 1\nodd digits -> n\n").unwrap();
+
+        assert_eq!(
+            rules,
+            Rules(vec![
+                Rule {
+                    when: Condition::Equals(7),
+                    then: vec![Output::Constant(7), Output::Plus(1)]
+                },
+                Rule {
+                    when: Condition::OddDigits,
+                    then: vec![Output::Same]
+                },
+            ])
+        );
+        assert_eq!(rules.apply(7), Some(vec![7, 8]));
+        assert_eq!(rules.apply(12), Some(vec![12]));
+    }
+
+    #[test]
+    fn it_rejects_bad_rules() {
+        assert_eq!(
+            parse("n == 0 -> 1\nzero -> 1").unwrap_err().to_string(),
+            "2:1: expected a condition, found `zero`"
+        );
+        assert_eq!(
+            parse("any -> n * two").unwrap_err().to_string(),
+            "1:12: expected a number, found `two`"
+        );
+        assert_eq!(
+            parse("any -> half").unwrap_err().to_string(),
+            "1:8: expected an output, found `half`"
+        );
+        assert_eq!(
+            parse("any -> left, right").unwrap_err().to_string(),
+            "1:1: `left` needs `even digits`, found `any`"
+        );
+        assert_eq!(
+            parse("any 1").unwrap_err().to_string(),
+            "1:1: expected `->` in `any 1`"
+        );
+    }
+}
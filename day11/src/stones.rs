@@ -0,0 +1,324 @@
+use crate::rules::Rules;
+use common::{parse, ParseError};
+use std::{collections::HashMap, fmt};
+
+/// How many stones are engraved with each number.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stones {
+    n_blinks: u32,
+    stones: HashMap<u128, u128>,
+}
+
+/// The stones after one blink.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Step {
+    pub blink: u32,
+    pub stones: u128,
+    pub distinct: usize,
+    pub largest: u128,
+}
+
+/// Why blinking stopped early.
+#[derive(Debug, PartialEq, Clone)]
+pub enum BlinkError {
+    /// A rule made a stone too big for a `u128`.
+    StoneOverflow { blink: u32, stone: u128 },
+    /// There are too many stones to count in a `u128`.
+    CountOverflow { blink: u32 },
+}
+
+impl fmt::Display for BlinkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlinkError::StoneOverflow { blink, stone } => {
+                write!(f, "blink {blink}: stone {stone} grows past a u128")
+            }
+            BlinkError::CountOverflow { blink } => {
+                write!(f, "blink {blink}: too many stones to count in a u128")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlinkError {}
+
+impl Stones {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let mut stones = HashMap::new();
+        let nums: Vec<u128> = input
+            .split_whitespace()
+            .map(|n| parse::number(input, n))
+            .collect::<Result<_, _>>()?;
+
+        for num in nums {
+            stones.entry(num).and_modify(|n| *n += 1).or_insert(1);
+        }
+
+        Ok(Stones {
+            n_blinks: 0,
+            stones,
+        })
+    }
+
+    /// The number of stones, or `None` if it doesn't fit in a `u128`.
+    pub fn count(&self) -> Option<u128> {
+        self.stones
+            .values()
+            .try_fold(0u128, |total, &count| total.checked_add(count))
+    }
+
+    fn step(&self) -> Result<Step, BlinkError> {
+        Ok(Step {
+            blink: self.n_blinks,
+            stones: self.count().ok_or(BlinkError::CountOverflow {
+                blink: self.n_blinks,
+            })?,
+            distinct: self.stones.len(),
+            largest: self.stones.keys().max().copied().unwrap_or_default(),
+        })
+    }
+
+    /// Blinks `n` times with the puzzle's rules, returning how many stones
+    /// there are afterwards.
+    pub fn blink(&mut self, n: usize) -> Result<u128, BlinkError> {
+        self.blink_with(&Rules::default(), n)?;
+        Ok(self.step()?.stones)
+    }
+
+    /// Blinks `n` times with `rules`, returning the stones after each blink.
+    /// Stops at the first blink that overflows, leaving the stones as they
+    /// were before it.
+    pub fn blink_with(&mut self, rules: &Rules, n: usize) -> Result<Vec<Step>, BlinkError> {
+        let mut steps = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            // We don't care about the order of the numbers,
+            // we only care about the number of appearances.
+            let blink = self.n_blinks + 1;
+            let mut next = HashMap::new();
+
+            for (&num, &count) in self.stones.iter() {
+                let stones = rules
+                    .apply(num)
+                    .ok_or(BlinkError::StoneOverflow { blink, stone: num })?;
+                for stone in stones {
+                    let total: &mut u128 = next.entry(stone).or_default();
+                    *total = total
+                        .checked_add(count)
+                        .ok_or(BlinkError::CountOverflow { blink })?;
+                }
+            }
+
+            let next = Stones {
+                n_blinks: blink,
+                stones: next,
+            };
+            steps.push(next.step()?);
+            *self = next;
+        }
+
+        Ok(steps)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Stones, ParseError> {
+    Stones::from(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules;
+
+    #[test]
+    fn it_parses() {
+        let input = "125 17".to_string();
+        let stones = Stones::from(&input).unwrap();
+
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 0,
+                stones: HashMap::from([(17, 1), (125, 1),]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_blinks_once() {
+        let input = "0 1 10 99 999".to_string();
+        let mut stones = Stones::from(&input).unwrap();
+        stones.blink(1).unwrap();
+
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 1,
+                stones: HashMap::from([(0, 1), (1, 2), (9, 2), (2024, 1), (2021976, 1),]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_blinks_multiple_times() {
+        let input = "125 17".to_string();
+        let mut stones = Stones::from(&input).unwrap();
+
+        stones.blink(2).unwrap();
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 2,
+                stones: HashMap::from([(0, 1), (253, 1), (2024, 1), (14168, 1),]),
+            }
+        );
+
+        stones.blink(1).unwrap();
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 3,
+                stones: HashMap::from([(512072, 1), (1, 1), (20, 1), (28676032, 1), (24, 1)]),
+            }
+        );
+
+        stones.blink(1).unwrap();
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 4,
+                stones: HashMap::from([
+                    (512, 1),
+                    (72, 1),
+                    (2024, 1),
+                    (2, 2),
+                    (0, 1),
+                    (4, 1),
+                    (2867, 1),
+                    (6032, 1),
+                ]),
+            }
+        );
+
+        stones.blink(1).unwrap();
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 5,
+                stones: HashMap::from([
+                    (1036288, 1),
+                    (7, 1),
+                    (2, 1),
+                    (20, 1),
+                    (24, 1),
+                    (4048, 2),
+                    (1, 1),
+                    (8096, 1),
+                    (28, 1),
+                    (67, 1),
+                    (60, 1),
+                    (32, 1),
+                ]),
+            }
+        );
+
+        stones.blink(1).unwrap();
+        assert_eq!(
+            stones,
+            Stones {
+                n_blinks: 6,
+                stones: HashMap::from([
+                    (2097446912, 1),
+                    (14168, 1),
+                    (4048, 1),
+                    (2, 4),
+                    (0, 2),
+                    (4, 1),
+                    (40, 2),
+                    (48, 2),
+                    (2024, 1),
+                    (80, 1),
+                    (96, 1),
+                    (8, 1),
+                    (6, 2),
+                    (7, 1),
+                    (3, 1),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_gets_number_of_stones() {
+        let input = "125 17".to_string();
+        let mut stones = Stones::from(&input).unwrap();
+
+        let n_stones = stones.blink(25).unwrap();
+        assert_eq!(n_stones, 55312);
+    }
+
+    #[test]
+    fn it_reports_each_step() {
+        let mut stones = Stones::from("125 17").unwrap();
+        let steps = stones.blink_with(&Rules::default(), 6).unwrap();
+
+        assert_eq!(
+            steps[3],
+            Step {
+                blink: 4,
+                stones: 9,
+                distinct: 8,
+                largest: 6032
+            }
+        );
+        assert_eq!(
+            steps.iter().map(|step| step.stones).collect::<Vec<_>>(),
+            vec![3, 4, 5, 9, 13, 22]
+        );
+        assert_eq!(steps[5].largest, 2097446912);
+    }
+
+    #[test]
+    fn it_stops_when_the_count_overflows() {
+        let mut stones = Stones::from("125 17").unwrap();
+
+        assert_eq!(
+            stones.blink_with(&Rules::default(), 1000),
+            Err(BlinkError::CountOverflow { blink: 212 })
+        );
+        assert_eq!(stones.n_blinks, 211);
+        assert_eq!(stones.step().unwrap().distinct, 54);
+        assert!(stones.count().is_some());
+    }
+
+    #[test]
+    fn it_stops_when_a_stone_overflows() {
+        let rules = rules::parse("any -> n * 10").unwrap();
+        let mut stones = Stones::from("1").unwrap();
+
+        let err = stones.blink_with(&rules, 100).unwrap_err();
+        assert_eq!(
+            err,
+            BlinkError::StoneOverflow {
+                blink: 39,
+                stone: 10u128.pow(38)
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "blink 39: stone 100000000000000000000000000000000000000 grows past a u128"
+        );
+    }
+
+    #[test]
+    fn it_blinks_thousands_of_times() {
+        // Two stones that swap forever
+        let rules = rules::parse("n == 1 -> 2\nn == 2 -> 1").unwrap();
+        let mut stones = Stones::from("1 2 2").unwrap();
+        let steps = stones.blink_with(&rules, 5000).unwrap();
+
+        assert_eq!(steps.len(), 5000);
+        assert_eq!(stones.blink(0), Ok(3));
+        assert_eq!(stones.stones, HashMap::from([(1, 1), (2, 2)]));
+    }
+}
@@ -0,0 +1,196 @@
+use common::ParseError;
+use grid::{Direction, Grid, Point};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// The garden plots and the points where each plant grows.
+#[derive(Debug, PartialEq)]
+pub struct Garden {
+    map: Grid<char>,
+    plants: HashMap<char, Vec<Point>>,
+}
+
+impl Garden {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |_, ch| {
+            if ch.is_ascii_uppercase() {
+                Ok(ch)
+            } else {
+                Err(format!("expected a plant, found `{ch}`"))
+            }
+        })?;
+
+        let mut plants = HashMap::new();
+        for (point, &ch) in map.iter() {
+            plants.entry(ch).or_insert_with(Vec::new).push(point);
+        }
+
+        Ok(Garden { map, plants })
+    }
+
+    /// Every region of touching plots growing the same plant.
+    pub fn regions(&self) -> Vec<HashSet<Point>> {
+        let mut regions = Vec::new();
+        let mut visited: HashSet<Point> =
+            HashSet::with_capacity(self.map.width() * self.map.height());
+        let mut queue: BTreeSet<Point> = BTreeSet::new();
+
+        for start in self.map.points() {
+            if visited.contains(&start) {
+                continue;
+            }
+
+            let plant = self.map[start];
+            let mut region = HashSet::from([start]);
+            visited.insert(start);
+            queue.insert(start);
+
+            while let Some(coords) = queue.pop_first() {
+                for test_coords in self.map.neighbours(coords) {
+                    if self.map[test_coords] == plant {
+                        visited.insert(test_coords);
+                        if region.insert(test_coords) {
+                            queue.insert(test_coords);
+                        }
+                    }
+                }
+            }
+
+            regions.push(region);
+        }
+
+        regions
+    }
+}
+
+/// The number of plot edges that don't touch another plot in the region.
+pub fn perimeter(region: &HashSet<Point>) -> u64 {
+    region
+        .iter()
+        .flat_map(|coords| Direction::ALL.map(|direction| coords.step(direction)))
+        .filter(|next| !region.contains(next))
+        .count() as u64
+}
+
+/// The number of straight sides of the region's fence. Each side is counted
+/// once, at its rightmost or lowest plot.
+pub fn sides(region: &HashSet<Point>) -> u64 {
+    let mut left_sides = 0u64;
+    let mut right_sides = 0u64;
+    let mut up_sides = 0u64;
+    let mut down_sides = 0u64;
+
+    for &coords in region.iter() {
+        let up = coords.step(Direction::Up);
+        let down = coords.step(Direction::Down);
+        let left = coords.step(Direction::Left);
+        let right = coords.step(Direction::Right);
+
+        if !region.contains(&up)
+            && (!region.contains(&right) || region.contains(&up.step(Direction::Right)))
+        {
+            up_sides += 1;
+        }
+
+        if !region.contains(&left)
+            && (!region.contains(&down) || region.contains(&down.step(Direction::Left)))
+        {
+            left_sides += 1;
+        }
+
+        if !region.contains(&down)
+            && (!region.contains(&right) || region.contains(&down.step(Direction::Right)))
+        {
+            down_sides += 1;
+        }
+
+        if !region.contains(&right)
+            && (!region.contains(&down) || region.contains(&down.step(Direction::Right)))
+        {
+            right_sides += 1;
+        }
+    }
+
+    up_sides + down_sides + left_sides + right_sides
+}
+
+pub fn parse(input: &str) -> Result<Garden, ParseError> {
+    Garden::from(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_garden() {
+        let input = "AAAA
+BBCD
+BBCC
+EEEC"
+            .to_string();
+        let garden = Garden::from(&input).unwrap();
+
+        assert_eq!(
+            garden,
+            Garden {
+                map: Grid::parse_chars(&input).unwrap(),
+                plants: HashMap::from([
+                    (
+                        'A',
+                        vec![
+                            Point::new(0, 0),
+                            Point::new(1, 0),
+                            Point::new(2, 0),
+                            Point::new(3, 0)
+                        ]
+                    ),
+                    (
+                        'B',
+                        vec![
+                            Point::new(0, 1),
+                            Point::new(1, 1),
+                            Point::new(0, 2),
+                            Point::new(1, 2)
+                        ]
+                    ),
+                    (
+                        'C',
+                        vec![
+                            Point::new(2, 1),
+                            Point::new(2, 2),
+                            Point::new(3, 2),
+                            Point::new(3, 3)
+                        ]
+                    ),
+                    ('D', vec![Point::new(3, 1)]),
+                    (
+                        'E',
+                        vec![Point::new(0, 3), Point::new(1, 3), Point::new(2, 3)]
+                    ),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_finds_regions() {
+        let garden = Garden::from("AAB\nCAB").unwrap();
+        let mut regions = garden
+            .regions()
+            .into_iter()
+            .map(|region| region.len())
+            .collect::<Vec<_>>();
+        regions.sort();
+
+        assert_eq!(regions, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn it_measures_perimeters_and_sides() {
+        let square = HashSet::from([Point::new(0, 0)]);
+        assert_eq!((perimeter(&square), sides(&square)), (4, 4));
+
+        let corner = HashSet::from([Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)]);
+        assert_eq!((perimeter(&corner), sides(&corner)), (8, 6));
+    }
+}
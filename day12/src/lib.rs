@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod garden;
+pub mod part1;
+pub mod part2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input<'a> = garden::Garden;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        garden::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
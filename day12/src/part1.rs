@@ -0,0 +1,42 @@
+use crate::garden::{perimeter, Garden};
+use common::Answer;
+
+/// The total price of fencing each region, its area times its perimeter.
+fn evaluate(garden: &Garden) -> u64 {
+    garden
+        .regions()
+        .iter()
+        .map(|region| perimeter(region) * region.len() as u64)
+        .sum()
+}
+
+pub fn solve(garden: &Garden) -> Answer {
+    evaluate(garden).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::garden;
+
+    #[test]
+    fn it_calculates_price() {
+        let input = "AA
+BA"
+        .to_string();
+        let garden = garden::parse(&input).unwrap();
+        let price = evaluate(&garden);
+
+        assert_eq!(price, 28);
+
+        let input = "AAAA
+BBCD
+BBCC
+EEEC"
+            .to_string();
+        let garden = garden::parse(&input).unwrap();
+        let price = evaluate(&garden);
+
+        assert_eq!(price, 140);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::garden::{sides, Garden};
+use common::Answer;
+
+/// The total price of fencing each region, its area times its number of sides.
+fn evaluate(garden: &Garden) -> u64 {
+    garden
+        .regions()
+        .iter()
+        .map(|region| sides(region) * region.len() as u64)
+        .sum()
+}
+
+pub fn solve(garden: &Garden) -> Answer {
+    evaluate(garden).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::garden;
+
+    #[test]
+    fn it_calculates_price() {
+        let input = "AA
+BA"
+        .to_string();
+        let garden = garden::parse(&input).unwrap();
+        let price = evaluate(&garden);
+
+        assert_eq!(price, 22);
+
+        let input = "AAAA
+BBCD
+BBCC
+EEEC"
+            .to_string();
+        let garden = garden::parse(&input).unwrap();
+        let price = evaluate(&garden);
+
+        assert_eq!(price, 80);
+
+        let input = "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE"
+            .to_string();
+        let garden = garden::parse(&input).unwrap();
+        let price = evaluate(&garden);
+
+        assert_eq!(price, 236);
+    }
+}
@@ -0,0 +1,220 @@
+use common::{parse, ParseError};
+use nalgebra::{Matrix2, Vector2};
+
+/// A claw machine: how far each button moves the claw, and where the prize is.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Claw {
+    button_a: (u64, u64),
+    button_b: (u64, u64),
+    prize: (u64, u64),
+}
+
+impl Claw {
+    fn from_puzzle_input(input: &str) -> Result<Vec<Self>, ParseError> {
+        input
+            .split("\n\n")
+            .filter(|claw_input| !claw_input.trim().is_empty())
+            .map(|claw_input| Self::from_lines(input, claw_input))
+            .collect()
+    }
+
+    fn from_lines(input: &str, claw_input: &str) -> Result<Self, ParseError> {
+        let mut lines = claw_input.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        let button_a = Self::parse_line(input, lines.next(), "Button A", "+")?;
+        let button_b = Self::parse_line(input, lines.next(), "Button B", "+")?;
+        let prize = Self::parse_line(input, lines.next(), "Prize", "=")?;
+
+        Ok(Claw {
+            button_a,
+            button_b,
+            prize,
+        })
+    }
+
+    /// Parses a line such as `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+    fn parse_line(
+        input: &str,
+        line: Option<&str>,
+        label: &str,
+        sign: &str,
+    ) -> Result<(u64, u64), ParseError> {
+        let expected = format!("`{label}: X{sign}.., Y{sign}..`");
+        let line = line.ok_or_else(|| ParseError::eof(input, &expected))?;
+        let values = line
+            .strip_prefix(label)
+            .and_then(|rest| rest.strip_prefix(":"))
+            .ok_or_else(|| ParseError::at(input, line, format!("expected {expected}")))?;
+        let (x, y) = parse::split_once(input, values, ",")?;
+
+        let coordinate = |value: &str, axis: &str| {
+            let value = value.trim();
+            match value.strip_prefix(axis).and_then(|v| v.strip_prefix(sign)) {
+                Some(n) => parse::number::<u64>(input, n),
+                None => Err(ParseError::at(
+                    input,
+                    value,
+                    format!("expected `{axis}{sign}`, found `{value}`"),
+                )),
+            }
+        };
+
+        Ok((coordinate(x, "X")?, coordinate(y, "Y")?))
+    }
+
+    /// The same machine with the prize's true position, far further away.
+    pub fn corrected(&self) -> Self {
+        let offset = 10000000000000u64;
+
+        Claw {
+            prize: (self.prize.0 + offset, self.prize.1 + offset),
+            ..self.clone()
+        }
+    }
+
+    /// How many times to press A and B to reach the prize, if it can be won.
+    pub fn solve(&self) -> Option<(u64, u64)> {
+        let m = Matrix2::new(
+            self.button_a.0 as f64,
+            self.button_b.0 as f64,
+            self.button_a.1 as f64,
+            self.button_b.1 as f64,
+        );
+        let y = Vector2::new(self.prize.0 as f64, self.prize.1 as f64);
+
+        let m_ = m.try_inverse();
+
+        match m_ {
+            Some(m_inv) => {
+                let x = m_inv * y;
+
+                let a = self.check_and_round(x[0]);
+                let b = self.check_and_round(x[1]);
+
+                // Rounding can hide an answer that is only nearly whole
+                match (a, b) {
+                    (Some(a), Some(b)) if self.reaches_prize(a, b) => Some((a, b)),
+                    _ => None,
+                }
+            }
+            None => None,
+        }
+    }
+
+    fn reaches_prize(&self, a: u64, b: u64) -> bool {
+        a * self.button_a.0 + b * self.button_b.0 == self.prize.0
+            && a * self.button_a.1 + b * self.button_b.1 == self.prize.1
+    }
+
+    fn check_and_round(&self, value: f64) -> Option<u64> {
+        let threshold = 0.0001;
+
+        if (value.fract().abs() < threshold) || ((1.0 - value.fract()).abs() < threshold) {
+            return Some(value.round() as u64);
+        }
+
+        None
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Claw>, ParseError> {
+    Claw::from_puzzle_input(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_puzzle_input() {
+        let input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176"
+            .to_string();
+        let claws = Claw::from_puzzle_input(&input).unwrap();
+
+        assert_eq!(
+            claws,
+            vec![
+                Claw {
+                    button_a: (94, 34),
+                    button_b: (22, 67),
+                    prize: (8400, 5400),
+                },
+                Claw {
+                    button_a: (26, 66),
+                    button_b: (67, 21),
+                    prize: (12748, 12176),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_solves() {
+        let input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400"
+            .to_string();
+        let claws = Claw::from_puzzle_input(&input).unwrap();
+        let claw = claws.first().unwrap();
+        let solution = claw.solve();
+
+        assert_eq!(solution, Some((80, 40)));
+    }
+
+    #[test]
+    fn it_doesnt_solve() {
+        let input = "Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176"
+            .to_string();
+        let claws = Claw::from_puzzle_input(&input).unwrap();
+        let claw = claws.first().unwrap();
+        let solution = claw.solve();
+
+        assert_eq!(solution, None);
+    }
+
+    #[test]
+    fn it_solves_corrected_claws() {
+        let input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176"
+            .to_string();
+        let claws = Claw::from_puzzle_input(&input).unwrap();
+
+        assert_eq!(claws[0].corrected().prize, (10000000008400, 10000000005400));
+        assert_eq!(claws[0].corrected().solve(), None);
+        assert_eq!(
+            claws[1].corrected().solve(),
+            Some((118679050709, 103199174542))
+        );
+    }
+
+    #[test]
+    fn it_rejects_bad_claws() {
+        let error = |input| Claw::from_puzzle_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("Button A: X+94, Y+34\nButton B: X+22, Y-67\nPrize: X=8400, Y=5400"),
+            "2:17: expected `Y+`, found `Y-67`"
+        );
+        assert_eq!(
+            error("Button A: X+94, Y+34\nPrize: X=8400, Y=5400"),
+            "2:1: expected `Button B: X+.., Y+..`"
+        );
+        assert_eq!(
+            error("Button A: X+94, Y+34\nButton B: X+22, Y+67"),
+            "2:21: expected `Prize: X=.., Y=..`, found end of input"
+        );
+    }
+}
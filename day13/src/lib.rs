@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod claw;
+pub mod part1;
+pub mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<claw::Claw>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        claw::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
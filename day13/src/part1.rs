@@ -0,0 +1,18 @@
+use crate::claw::Claw;
+use common::Answer;
+
+pub fn solve(claws: &[Claw]) -> Answer {
+    let mut a_presses = 0;
+    let mut b_presses = 0;
+    let a_tokens = 3;
+    let b_tokens = 1;
+
+    for claw in claws {
+        if let Some(result) = claw.solve() {
+            a_presses += result.0;
+            b_presses += result.1;
+        }
+    }
+
+    (a_presses * a_tokens + b_presses * b_tokens).into()
+}
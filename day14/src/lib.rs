@@ -0,0 +1,35 @@
+use common::{Answer, ParseError, Solution};
+use grid::Frames;
+
+pub mod part1;
+pub mod part2;
+pub mod robot;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input<'a> = Vec<robot::Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        robot::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
+
+/// Draws the robots moving around the room for `aoc run --visualize`: the
+/// first 100 seconds for part 1, and up to the Christmas tree for part 2.
+pub fn frames(part: u8, input: &str) -> Option<Result<Frames, ParseError>> {
+    match part {
+        1 => Some(robot::parse(input).map(|robots| part1::frames(&robots))),
+        2 => Some(robot::parse(input).map(|robots| part2::frames(&robots))),
+        _ => None,
+    }
+}
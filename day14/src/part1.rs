@@ -0,0 +1,118 @@
+use crate::robot::Robot;
+use common::Answer;
+use grid::{Cell, Colour, Frame, Frames, Grid, Point};
+use std::cmp::Ordering;
+
+/// Draws how many robots are on each tile, coloured by quadrant. Robots on
+/// the middle row or column are in no quadrant and drawn in grey.
+fn frame(robots: &[Robot], seconds: usize) -> Frame {
+    let (x_max, y_max) = robots.first().map_or((0, 0), |robot| robot.room);
+    let mut counts = Grid::new(x_max as usize + 1, y_max as usize + 1, 0);
+    for robot in robots {
+        counts[Point::from(robot.pos)] += 1;
+    }
+
+    counts
+        .frame(|point, &n| {
+            let ch = match n {
+                0 => '.',
+                1..=9 => char::from_digit(n, 10).unwrap(),
+                _ => '+',
+            };
+            let colour = match (point.x.cmp(&(x_max / 2)), point.y.cmp(&(y_max / 2))) {
+                _ if n == 0 => Colour::Grey,
+                (Ordering::Equal, _) | (_, Ordering::Equal) => Colour::Grey,
+                (Ordering::Less, Ordering::Less) => Colour::Red,
+                (Ordering::Greater, Ordering::Less) => Colour::Green,
+                (Ordering::Less, Ordering::Greater) => Colour::Yellow,
+                (Ordering::Greater, Ordering::Greater) => Colour::Blue,
+            };
+
+            Cell::new(ch, colour)
+        })
+        .with_caption(format!("{seconds} seconds"))
+}
+
+/// The room after each of the 100 seconds, starting from the robots' initial
+/// positions.
+pub fn frames(robots: &[Robot]) -> Frames {
+    let mut robots = robots.to_vec();
+
+    Box::new((0..=100).map(move |seconds| {
+        if seconds > 0 {
+            for robot in robots.iter_mut() {
+                robot.step(1);
+            }
+        }
+        frame(&robots, seconds)
+    }))
+}
+
+pub fn solve(robots: &[Robot]) -> Answer {
+    let n_steps = 100;
+    let mut robots = robots.to_vec();
+    let (x_max, y_max) = robots.first().map_or((0, 0), |robot| robot.room);
+
+    for robot in &mut robots {
+        robot.step(n_steps);
+    }
+
+    let x_boundary = x_max / 2;
+    let y_boundary = y_max / 2;
+
+    let mut q1 = 0u32;
+    let mut q2 = 0u32;
+    let mut q3 = 0u32;
+    let mut q4 = 0u32;
+
+    for robot in robots {
+        match robot.pos {
+            (x, y) if x < x_boundary && y < y_boundary => q1 += 1,
+            (x, y) if x > x_boundary && y < y_boundary => q2 += 1,
+            (x, y) if x < x_boundary && y > y_boundary => q3 += 1,
+            (x, y) if x > x_boundary && y > y_boundary => q4 += 1,
+            _ => {}
+        }
+    }
+
+    (q1 * q2 * q3 * q4).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::robot;
+
+    #[test]
+    fn it_draws_the_room() {
+        let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+        let robots = robot::parse_in_room(input, 10, 6).unwrap();
+        let frames = frames(&robots).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 101);
+        assert_eq!(
+            frames[100].to_string(),
+            "......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....
+100 seconds"
+        );
+        assert_eq!(frames[100].cells[Point::new(6, 0)].colour, Colour::Green);
+        assert_eq!(frames[100].cells[Point::new(5, 4)].colour, Colour::Grey);
+    }
+}
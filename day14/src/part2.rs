@@ -0,0 +1,84 @@
+use crate::robot::Robot;
+use common::Answer;
+use grid::{Cell, Colour, Frame, Frames, Grid, Point};
+use itertools::Itertools;
+use std::iter;
+
+fn frame(robots: &[Robot], seconds: usize) -> Frame {
+    let (x_max, y_max) = robots.first().map_or((0, 0), |robot| robot.room);
+    let mut occupied = Grid::new(x_max as usize + 1, y_max as usize + 1, false);
+    for robot in robots {
+        occupied[Point::from(robot.pos)] = true;
+    }
+
+    occupied
+        .frame(|_, &robot| {
+            if robot {
+                Cell::new('#', Colour::Green)
+            } else {
+                Cell::new('.', Colour::Grey)
+            }
+        })
+        .with_caption(format!("{seconds} seconds"))
+}
+
+/// The room after each second, until no two robots share a tile and the
+/// Christmas tree appears.
+pub fn frames(robots: &[Robot]) -> Frames {
+    let mut robots = robots.to_vec();
+    let mut seconds = 0;
+    let mut done = false;
+
+    Box::new(iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        if seconds > 0 {
+            for robot in robots.iter_mut() {
+                robot.step(1);
+            }
+            done = robots.iter().map(|robot| robot.pos).all_unique();
+        }
+        seconds += 1;
+
+        Some(frame(&robots, seconds - 1))
+    }))
+}
+
+pub fn solve(robots: &[Robot]) -> Answer {
+    let mut robots = robots.to_vec();
+
+    let mut n = 0;
+    loop {
+        for robot in robots.iter_mut() {
+            robot.step(1);
+        }
+        n += 1;
+
+        if robots.iter().map(|robot| robot.pos).all_unique() {
+            break;
+        }
+    }
+
+    n.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::robot;
+
+    #[test]
+    fn it_draws_until_the_robots_spread_out() {
+        let input = "p=0,0 v=1,0
+p=1,0 v=0,0
+p=2,1 v=0,0";
+        let robots = robot::parse_in_room(input, 2, 1).unwrap();
+        let frames = frames(&robots).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[1].to_string(), ".#.\n..#\n1 seconds");
+        assert_eq!(frames[2].to_string(), ".##\n..#\n2 seconds");
+    }
+}
@@ -0,0 +1,217 @@
+// Puzzle input is 101 wide and 103 tall.
+
+use common::{parse, ParseError};
+
+/// A robot's position and velocity, and the size of the room it patrols.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Robot {
+    pub(crate) pos: (i32, i32),
+    vel: (i32, i32),
+    pub(crate) room: (i32, i32),
+}
+
+impl Robot {
+    fn parse_robots(input: &str, x_max: i32, y_max: i32) -> Result<Vec<Self>, ParseError> {
+        let mut robots = Vec::new();
+
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let mut parts = line.split_whitespace();
+            let (Some(pos), Some(vel)) = (parts.next(), parts.next()) else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected `p=<x>,<y> v=<x>,<y>`",
+                ));
+            };
+
+            let (x, y) = Self::parse_vector(input, pos, "p=")?;
+            if !(0..=x_max).contains(&x) || !(0..=y_max).contains(&y) {
+                return Err(ParseError::at(
+                    input,
+                    pos,
+                    format!(
+                        "robot at {x},{y} is outside the {}x{} room",
+                        x_max + 1,
+                        y_max + 1
+                    ),
+                ));
+            }
+
+            robots.push(Robot {
+                pos: (x, y),
+                vel: Self::parse_vector(input, vel, "v=")?,
+                room: (x_max, y_max),
+            });
+        }
+
+        Ok(robots)
+    }
+
+    fn parse_vector(input: &str, part: &str, prefix: &str) -> Result<(i32, i32), ParseError> {
+        let coords = part.strip_prefix(prefix).ok_or_else(|| {
+            ParseError::at(input, part, format!("expected `{prefix}`, found `{part}`"))
+        })?;
+        let (x, y) = parse::split_once(input, coords, ",")?;
+
+        Ok((parse::number(input, x)?, parse::number(input, y)?))
+    }
+
+    /// Moves the robot `n` seconds on, wrapping around the room's edges.
+    pub fn step(&mut self, n: usize) {
+        let n = n as i32;
+
+        let x_delta = (self.vel.0 * n) % (self.room.0 + 1);
+        let y_delta = (self.vel.1 * n) % (self.room.1 + 1);
+
+        let mut x_next = self.pos.0 + x_delta;
+        let mut y_next = self.pos.1 + y_delta;
+
+        // Check underflow
+        if x_next < 0 {
+            x_next += self.room.0 + 1;
+        }
+        if y_next < 0 {
+            y_next += self.room.1 + 1;
+        }
+
+        // Check overflow
+        if x_next > self.room.0 {
+            x_next %= self.room.0 + 1;
+        }
+        if y_next > self.room.1 {
+            y_next %= self.room.1 + 1;
+        }
+
+        self.pos = (x_next, y_next);
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_in_room(input, 100, 102)
+}
+
+/// Parses robots in a room whose last tile is at `x_max`, `y_max`.
+pub fn parse_in_room(input: &str, x_max: i32, y_max: i32) -> Result<Vec<Robot>, ParseError> {
+    Robot::parse_robots(input, x_max, y_max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_robots() {
+        let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=3,0 v=-2,-2
+p=3,0 v=-1,-2"
+            .to_string();
+        let robots = Robot::parse_robots(&input, 10, 6).unwrap();
+
+        assert_eq!(
+            robots,
+            vec![
+                Robot {
+                    pos: (0, 4),
+                    vel: (3, -3),
+                    room: (10, 6),
+                },
+                Robot {
+                    pos: (6, 3),
+                    vel: (-1, -3),
+                    room: (10, 6),
+                },
+                Robot {
+                    pos: (3, 0),
+                    vel: (-2, -2),
+                    room: (10, 6),
+                },
+                Robot {
+                    pos: (3, 0),
+                    vel: (-1, -2),
+                    room: (10, 6),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn it_moves_within_the_room() {
+        let input = "p=0,0 v=1,1".to_string();
+        let mut robots = Robot::parse_robots(&input, 10, 6).unwrap();
+        let robot = &mut robots[0];
+
+        robot.step(1);
+        assert_eq!(
+            *robot,
+            Robot {
+                pos: (1, 1),
+                vel: (1, 1),
+                room: (10, 6),
+            }
+        );
+
+        robot.step(2);
+        assert_eq!(
+            *robot,
+            Robot {
+                pos: (3, 3),
+                vel: (1, 1),
+                room: (10, 6),
+            }
+        );
+    }
+
+    #[test]
+    fn it_wraps_the_room() {
+        let input = "p=0,0 v=-1,0".to_string();
+        let mut robots = Robot::parse_robots(&input, 10, 6).unwrap();
+        let robot = &mut robots[0];
+
+        robot.step(1);
+        assert_eq!(robot.pos, (10, 0));
+
+        robot.vel = (0, -1);
+        robot.step(1);
+        assert_eq!(robot.pos, (10, 6));
+
+        robot.vel = (1, 0);
+        robot.step(1);
+        assert_eq!(robot.pos, (0, 6));
+
+        robot.vel = (0, 1);
+        robot.step(1);
+        assert_eq!(robot.pos, (0, 0));
+
+        robot.vel = (11, 0);
+        robot.step(100);
+        assert_eq!(robot.pos, (0, 0));
+    }
+
+    #[test]
+    fn it_rejects_bad_robots() {
+        let error = |input| Robot::parse_robots(input, 10, 6).unwrap_err().to_string();
+
+        assert_eq!(
+            error("p=0,4 v=3,-3\np=6,3"),
+            "2:1: expected `p=<x>,<y> v=<x>,<y>`"
+        );
+        assert_eq!(
+            error("p=0,4 v=3,-3\np=6,3 w=-1,-3"),
+            "2:7: expected `v=`, found `w=-1,-3`"
+        );
+        assert_eq!(error("p=0,4 v=3;-3"), "1:9: expected `,` in `3;-3`");
+        assert_eq!(
+            error("p=0,four v=3,-3"),
+            "1:5: expected a number, found `four`"
+        );
+        assert_eq!(
+            error("p=0,4 v=3,-3\np=11,3 v=3,-3"),
+            "2:1: robot at 11,3 is outside the 11x7 room"
+        );
+        assert_eq!(
+            error("p=0,-1 v=3,-3"),
+            "1:1: robot at 0,-1 is outside the 11x7 room"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use common::{Answer, ParseError, Solution};
+use grid::Frames;
+
+pub mod part1;
+pub mod part2;
+pub mod warehouse;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = warehouse::Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        warehouse::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
+
+/// Draws the robot pushing boxes around for `aoc run --visualize`, in the
+/// narrow warehouse for part 1 and the wide one for part 2.
+pub fn frames(part: u8, input: &str) -> Option<Result<Frames, ParseError>> {
+    let parsed = match part {
+        1 => warehouse::parse(input),
+        2 => warehouse::parse(input).map(|warehouse| warehouse.widen()),
+        _ => return None,
+    };
+
+    Some(parsed.map(|warehouse| warehouse::frames(&warehouse)))
+}
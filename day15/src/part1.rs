@@ -0,0 +1,31 @@
+use crate::warehouse::Warehouse;
+use common::Answer;
+
+pub fn solve(warehouse: &Warehouse) -> Answer {
+    let mut warehouse = warehouse.clone();
+    warehouse.apply_all();
+
+    warehouse.gps().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::warehouse;
+
+    #[test]
+    fn it_sums_gps_coordinates() {
+        let input = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+        assert_eq!(solve(&warehouse::parse(input).unwrap()), Answer::Int(2028));
+    }
+}
@@ -0,0 +1,30 @@
+use crate::warehouse::Warehouse;
+use common::Answer;
+
+pub fn solve(warehouse: &Warehouse) -> Answer {
+    let mut warehouse = warehouse.widen();
+    warehouse.apply_all();
+
+    warehouse.gps().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::warehouse;
+
+    #[test]
+    fn it_sums_wide_gps_coordinates() {
+        let input = "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+        assert_eq!(solve(&warehouse::parse(input).unwrap()), Answer::Int(618));
+    }
+}
@@ -0,0 +1,384 @@
+use common::ParseError;
+use grid::{Cell, Colour, Direction, Frame, Frames, Grid, Point};
+use itertools::Itertools;
+use std::iter;
+
+/// The warehouse map, with boxes drawn as `O` or, once widened, as `[]`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Warehouse {
+    map: Grid<char>,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+impl Warehouse {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        // Split input into warehouse and robot sequence
+        let (map, moves) = input.split_once("\n\n").unwrap_or((input, ""));
+
+        // Parse warehouse
+        let map = Grid::parse(map, |_, c| match c {
+            '#' | '.' | 'O' | '@' => Ok(c),
+            _ => Err(format!("unexpected `{c}` in the warehouse")),
+        })?;
+
+        // Parse robot's movements
+        let mut seq = Vec::new();
+        for (i, ch) in moves.char_indices() {
+            match Direction::from_arrow(ch) {
+                Some(dir) => seq.push(dir),
+                None if ch.is_whitespace() => {}
+                None => {
+                    return Err(ParseError::at(
+                        input,
+                        &moves[i..],
+                        format!("expected a move (`<`, `>`, `^` or `v`), found `{ch}`"),
+                    ))
+                }
+            }
+        }
+
+        let robot = map
+            .find(&'@')
+            .ok_or_else(|| ParseError::eof(input, "a robot (`@`)"))?;
+
+        Ok(Warehouse {
+            map,
+            robot,
+            moves: seq,
+        })
+    }
+
+    /// The same warehouse with everything except the robot twice as wide.
+    pub fn widen(&self) -> Self {
+        let map = Grid::from_fn(self.map.width() * 2, self.map.height(), |p| {
+            match (self.map[Point::new(p.x / 2, p.y)], p.x % 2) {
+                ('O', 0) => '[',
+                ('O', _) => ']',
+                ('@', 0) => '@',
+                ('@', _) => '.',
+                (c, _) => c,
+            }
+        });
+
+        Warehouse {
+            map,
+            robot: Point::new(self.robot.x * 2, self.robot.y),
+            moves: self.moves.clone(),
+        }
+    }
+
+    pub fn robot(&self) -> Point {
+        self.robot
+    }
+
+    pub fn moves(&self) -> &[Direction] {
+        &self.moves
+    }
+
+    /// Makes every move in the robot's sequence.
+    pub fn apply_all(&mut self) {
+        for instruction in self.moves.clone() {
+            self.apply(instruction);
+        }
+    }
+
+    /// Moves the robot one step, pushing any boxes in the way.
+    pub fn apply(&mut self, instruction: Direction) {
+        let robot = self.robot;
+        let next = robot.step(instruction);
+
+        if self.map[next] == '#' {
+            return;
+        } else if self.map[next] == '.' {
+            self.map[robot] = '.';
+            self.map[next] = '@';
+            self.robot = next;
+            return;
+        }
+
+        self.shift(next, instruction);
+
+        if self.map[next] == '.' {
+            self.map[robot] = '.';
+            self.map[next] = '@';
+            self.robot = next;
+        }
+    }
+
+    fn shift(&mut self, from: Point, instruction: Direction) {
+        if let Some(moves) = self.can_move(from, instruction) {
+            let moves = moves.into_iter().unique().collect::<Vec<_>>();
+            for point in moves {
+                let next = point.step(instruction);
+                (self.map[point], self.map[next]) = (self.map[next], self.map[point]);
+            }
+        }
+    }
+
+    /// Every part of a box that moves if the box at `point` is pushed, in an
+    /// order they can be moved one by one, or `None` if a wall is in the way.
+    fn can_move(&self, point: Point, instruction: Direction) -> Option<Vec<Point>> {
+        let next = point.step(instruction);
+
+        let other = match (self.map[point], instruction.is_horizontal()) {
+            ('[', false) => Some(point.step(Direction::Right)),
+            (']', false) => Some(point.step(Direction::Left)),
+            _ => None,
+        };
+        let other_next = other.map(|other| other.step(instruction));
+
+        match (self.map[next], other, other_next) {
+            ('#', _, _) => return None,
+            (_, _, Some(other_next)) if self.map[other_next] == '#' => return None,
+
+            ('.', _, None) => return Some(vec![point]),
+            ('.', Some(other), Some(other_next)) if self.map[other_next] == '.' => {
+                return Some(vec![point, other])
+            }
+
+            _ => (),
+        }
+
+        let mut all_moves = vec![];
+        if self.map[next] != '.' {
+            all_moves.extend(self.can_move(next, instruction)?);
+        }
+        if let Some(other_next) = other_next {
+            if self.map[other_next] != '.' {
+                all_moves.extend(self.can_move(other_next, instruction)?);
+            }
+        }
+
+        all_moves.push(point);
+        if let Some(other) = other {
+            all_moves.push(other);
+        }
+        Some(all_moves)
+    }
+
+    /// The sum of every box's GPS coordinate, measured from its left edge.
+    pub fn gps(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, c)| **c == '[' || **c == 'O')
+            .map(|(p, _)| p.y as usize * 100 + p.x as usize)
+            .sum()
+    }
+
+    /// Draws the warehouse as it appears in the puzzle input.
+    pub fn render(&self) -> String {
+        self.map.to_string()
+    }
+
+    pub fn frame(&self) -> Frame {
+        self.map.frame(|_, &c| match c {
+            'O' | '[' | ']' => Cell::new(c, Colour::Yellow),
+            '@' => Cell::new(c, Colour::Red),
+            '.' => Cell::new(c, Colour::Grey),
+            _ => Cell::plain(c),
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Warehouse, ParseError> {
+    Warehouse::from(input)
+}
+
+/// The warehouse before the robot moves and after each of its moves.
+pub fn frames(warehouse: &Warehouse) -> Frames {
+    let mut warehouse = warehouse.clone();
+    let moves = warehouse.moves.clone();
+    let n_moves = moves.len();
+    let first = warehouse.frame().with_caption(format!("Move 0/{n_moves}"));
+
+    let steps = moves.into_iter().enumerate().map(move |(i, step)| {
+        warehouse.apply(step);
+        warehouse
+            .frame()
+            .with_caption(format!("Move {}/{n_moves}: {}", i + 1, step.arrow()))
+    });
+
+    Box::new(iter::once(first).chain(steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn after_moves(input: &str) -> String {
+        let mut warehouse = Warehouse::from(input).unwrap();
+        warehouse.apply_all();
+        warehouse.render()
+    }
+
+    #[test]
+    fn it_parses_the_input() {
+        let input = "####
+#.O#
+##@#
+####
+
+<^^>>>v
+v<v>>v<<";
+        let warehouse = Warehouse::from(input).unwrap();
+
+        assert_eq!(
+            warehouse.render(),
+            "####
+#.O#
+##@#
+####"
+        );
+        assert_eq!(warehouse.robot(), Point::new(2, 2));
+        assert_eq!(
+            warehouse.moves(),
+            vec![
+                Direction::Left,
+                Direction::Up,
+                Direction::Up,
+                Direction::Right,
+                Direction::Right,
+                Direction::Right,
+                Direction::Down,
+                Direction::Down,
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+                Direction::Left,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_pushes_boxes_into_free_space() {
+        assert_eq!(
+            after_moves("####\n#.O#\n#.@#\n####\n\n<"),
+            "####\n#.O#\n#@.#\n####"
+        );
+        assert_eq!(
+            after_moves("####\n#.O#\n#.@#\n####\n\n>v^"),
+            "####\n#.O#\n#.@#\n####"
+        );
+        assert_eq!(
+            after_moves("####\n#..#\n#.O#\n#.@#\n####\n\n^"),
+            "####\n#.O#\n#.@#\n#..#\n####"
+        );
+        assert_eq!(
+            after_moves("####\n#..#\n#.O#\n#.O#\n#.@#\n####\n\n^"),
+            "####\n#.O#\n#.O#\n#.@#\n#..#\n####"
+        );
+        assert_eq!(
+            after_moves("####\n#..#\n#.##\n#.O#\n#.@#\n####\n\n^"),
+            "####\n#..#\n#.##\n#.O#\n#.@#\n####"
+        );
+    }
+
+    #[test]
+    fn it_completes_a_sequence() {
+        let input = "####
+#.O#
+#.@#
+####
+
+<<^^>v><^";
+
+        assert_eq!(
+            after_moves(input),
+            "####
+#@O#
+#..#
+####"
+        );
+    }
+
+    #[test]
+    fn it_runs_a_sequence() {
+        let input = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+        let mut warehouse = Warehouse::from(input).unwrap();
+        warehouse.apply_all();
+
+        assert_eq!(warehouse.robot(), Point::new(4, 4));
+        assert_eq!(warehouse.gps(), 2028);
+    }
+
+    #[test]
+    fn it_pushes_wide_boxes() {
+        let input = "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let mut warehouse = Warehouse::from(input).unwrap().widen();
+        assert_eq!(warehouse.robot(), Point::new(10, 3));
+
+        warehouse.apply_all();
+        assert_eq!(
+            warehouse.render(),
+            "##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############"
+        );
+        assert_eq!(warehouse.gps(), 105 + 207 + 306);
+    }
+
+    #[test]
+    fn it_rejects_bad_warehouses() {
+        let error = |input| Warehouse::from(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("#####\n#.@x#\n#####\n\n<^"),
+            "2:4: unexpected `x` in the warehouse"
+        );
+        assert_eq!(
+            error("#####\n#.@.#\n#####\n\n<^\nv>x"),
+            "6:3: expected a move (`<`, `>`, `^` or `v`), found `x`"
+        );
+        assert_eq!(
+            error("#####\n\n<^"),
+            "3:3: expected a robot (`@`), found end of input"
+        );
+    }
+
+    #[test]
+    fn it_draws_each_move() {
+        let input = "#####
+#.O@#
+#####
+
+<>";
+        let narrow = frames(&Warehouse::from(input).unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(narrow.len(), 3);
+        assert_eq!(narrow[0].to_string(), "#####\n#.O@#\n#####\nMove 0/2");
+        assert_eq!(narrow[1].to_string(), "#####\n#O@.#\n#####\nMove 1/2: <");
+        assert_eq!(narrow[2].to_string(), "#####\n#O.@#\n#####\nMove 2/2: >");
+        assert_eq!(narrow[2].cells[Point::new(1, 1)].colour, Colour::Yellow);
+
+        let wide = frames(&Warehouse::from(input).unwrap().widen()).collect::<Vec<_>>();
+        assert_eq!(
+            wide[1].to_string(),
+            "##########\n##.[]@..##\n##########\nMove 1/2: <"
+        );
+    }
+}
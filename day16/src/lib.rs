@@ -0,0 +1,35 @@
+use common::{Answer, ParseError, Solution};
+use grid::Frames;
+
+pub mod maze;
+pub mod part1;
+pub mod part2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input<'a> = maze::Maze;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        maze::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
+
+/// Draws the reindeer running the maze for `aoc run --visualize`. Part 2 ends
+/// with every tile on any of the best paths.
+pub fn frames(part: u8, input: &str) -> Option<Result<Frames, ParseError>> {
+    match part {
+        1 => Some(maze::parse(input).map(|maze| part1::frames(&maze))),
+        2 => Some(maze::parse(input).map(|maze| part2::frames(&maze))),
+        _ => None,
+    }
+}
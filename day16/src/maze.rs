@@ -0,0 +1,184 @@
+use common::ParseError;
+use grid::{Cell, Colour, Direction, Frame, Grid, Point};
+use std::collections::HashSet;
+
+/// The maze, with the reindeer's start and end tiles.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Maze {
+    pub(crate) start: Point,
+    pub(crate) end: Point,
+    walls: Grid<bool>,
+}
+
+impl Maze {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, |_, ch| match ch {
+            'S' | 'E' | '#' | '.' => Ok(ch),
+            _ => Err(format!("unexpected `{ch}` in the maze")),
+        })?;
+
+        let start = tiles
+            .find(&'S')
+            .ok_or_else(|| ParseError::eof(input, "a start tile (`S`)"))?;
+        let end = tiles
+            .find(&'E')
+            .ok_or_else(|| ParseError::eof(input, "an end tile (`E`)"))?;
+
+        Ok(Maze {
+            start,
+            end,
+            walls: tiles.map(|&ch| ch == '#'),
+        })
+    }
+
+    fn is_open(&self, point: Point) -> bool {
+        self.walls.get(point) == Some(&false)
+    }
+
+    /// Draws the maze with the tiles the reindeer has walked in cyan.
+    pub(crate) fn frame(&self, walked: &HashSet<Point>, (pos, facing): State) -> Frame {
+        self.walls.frame(|point, &wall| {
+            if point == pos {
+                Cell::new(facing.arrow(), Colour::Yellow)
+            } else if wall {
+                Cell::new('#', Colour::Grey)
+            } else if walked.contains(&point) {
+                Cell::new('O', Colour::Cyan)
+            } else if point == self.end {
+                Cell::new('E', Colour::Green)
+            } else {
+                Cell::plain('.')
+            }
+        })
+    }
+}
+
+/// A reindeer's position and the way it is facing.
+pub(crate) type State = (Point, Direction);
+
+/// The moves from `state`: stepping forward scores 1, and turning to face an
+/// open tile scores 1000.
+pub(crate) fn moves(maze: &Maze, &(pos, facing): &State) -> Vec<(State, u32)> {
+    let mut moves = Vec::new();
+
+    if maze.is_open(pos.step(facing)) {
+        moves.push(((pos.step(facing), facing), 1));
+    }
+
+    for turned in [facing.turn_left(), facing.turn_right()] {
+        if maze.is_open(pos.step(turned)) {
+            moves.push(((pos, turned), 1000));
+        }
+    }
+
+    moves
+}
+
+/// The reindeer walking `path`, one move per frame.
+pub(crate) fn walk(maze: Maze, path: Vec<State>) -> impl Iterator<Item = Frame> {
+    let mut walked = HashSet::new();
+    let mut score = 0;
+    let mut previous = None;
+
+    path.into_iter().map(move |state| {
+        if let Some(previous) = previous.replace(state) {
+            score += if previous.0 == state.0 { 1000 } else { 1 };
+        }
+        walked.insert(state.0);
+
+        maze.frame(&walked, state)
+            .with_caption(format!("Score {score}"))
+    })
+}
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    Maze::from(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_maze() {
+        let input = "#####
+        #S.E#
+        #####"
+            .to_string();
+        let maze = Maze::from(&input).unwrap();
+
+        assert_eq!(maze.start, Point::new(1, 1));
+        assert_eq!(maze.end, Point::new(3, 1));
+        assert_eq!(
+            maze.walls.find_all(&true).collect::<HashSet<_>>(),
+            HashSet::from([
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+                Point::new(4, 0),
+                Point::new(0, 1),
+                Point::new(4, 1),
+                Point::new(0, 2),
+                Point::new(1, 2),
+                Point::new(2, 2),
+                Point::new(3, 2),
+                Point::new(4, 2),
+            ]),
+        );
+
+        let input = "###############
+        #.......#....E#
+        #.#.###.#.###.#
+        #.....#.#...#.#
+        #.###.#####.#.#
+        #.#.#.......#.#
+        #.#.#####.###.#
+        #...........#.#
+        ###.#.#####.#.#
+        #...#.....#.#.#
+        #.#.#.###.#.#.#
+        #.....#...#.#.#
+        #.###.#.#.#.#.#
+        #S..#.....#...#
+        ###############"
+            .to_string();
+        let maze = Maze::from(&input).unwrap();
+
+        assert_eq!(maze.start, Point::new(1, 13));
+        assert_eq!(maze.end, Point::new(13, 1));
+        assert!(maze.walls[Point::new(4, 9)]);
+    }
+
+    #[test]
+    fn it_moves_and_turns() {
+        let input = "#####
+        #S.E#
+        #.###
+        #####";
+        let maze = Maze::from(input).unwrap();
+
+        assert_eq!(
+            moves(&maze, &(maze.start, Direction::Right)),
+            vec![
+                ((Point::new(2, 1), Direction::Right), 1),
+                ((maze.start, Direction::Down), 1000),
+            ]
+        );
+        assert_eq!(
+            moves(&maze, &(maze.start, Direction::Up)),
+            vec![((maze.start, Direction::Right), 1000)]
+        );
+    }
+
+    #[test]
+    fn it_rejects_bad_mazes() {
+        let error = |input| Maze::from(input).unwrap_err().to_string();
+
+        assert_eq!(error("####\n#S?#\n####"), "2:3: unexpected `?` in the maze");
+        assert_eq!(
+            error("####\n#S.#\n####"),
+            "3:5: expected an end tile (`E`), found end of input"
+        );
+    }
+}
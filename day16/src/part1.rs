@@ -0,0 +1,114 @@
+use crate::maze::{moves, walk, Maze};
+use common::Answer;
+use grid::{Direction, Frames};
+
+fn lowest_score(maze: &Maze) -> Option<u32> {
+    let start = (maze.start, Direction::Right);
+
+    search::dijkstra(
+        &start,
+        |state| moves(maze, state),
+        |&(pos, _)| pos == maze.end,
+    )
+    .map(|(_, score)| score)
+}
+
+/// The reindeer following one of the best paths to the end tile.
+pub fn frames(maze: &Maze) -> Frames {
+    let start = (maze.start, Direction::Right);
+    let path = search::dijkstra(
+        &start,
+        |state| moves(maze, state),
+        |&(pos, _)| pos == maze.end,
+    )
+    .map_or(vec![start], |(path, _)| path);
+
+    Box::new(walk(maze.clone(), path))
+}
+
+pub fn solve(maze: &Maze) -> Answer {
+    lowest_score(maze).map_or_else(
+        || Answer::Error("no path from the start to the end tile".to_string()),
+        Into::into,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze;
+
+    #[test]
+    fn it_solves() {
+        let input = "###############
+        #.......#....E#
+        #.#.###.#.###.#
+        #.....#.#...#.#
+        #.###.#####.#.#
+        #.#.#.......#.#
+        #.#.#####.###.#
+        #...........#.#
+        ###.#.#####.#.#
+        #...#.....#.#.#
+        #.#.#.###.#.#.#
+        #.....#...#.#.#
+        #.###.#.#.#.#.#
+        #S..#.....#...#
+        ###############"
+            .to_string();
+        let maze = maze::parse(&input).unwrap();
+        assert_eq!(lowest_score(&maze), Some(7036));
+
+        let input = "#################
+        #...#...#...#..E#
+        #.#.#.#.#.#.#.#.#
+        #.#.#.#...#...#.#
+        #.#.#.#.###.#.#.#
+        #...#.#.#.....#.#
+        #.#.#.#.#.#####.#
+        #.#...#.#.#.....#
+        #.#.#####.#.###.#
+        #.#.#.......#...#
+        #.#.###.#####.###
+        #.#.#...#.....#.#
+        #.#.#.#####.###.#
+        #.#.#.........#.#
+        #.#.#.#########.#
+        #S#.............#
+        #################"
+            .to_string();
+        let maze = maze::parse(&input).unwrap();
+        assert_eq!(lowest_score(&maze), Some(11048));
+    }
+
+    #[test]
+    fn it_reports_a_walled_off_end() {
+        let maze = maze::parse("#####\n#S#E#\n#####").unwrap();
+
+        assert_eq!(
+            solve(&maze),
+            Answer::Error("no path from the start to the end tile".to_string())
+        );
+    }
+
+    #[test]
+    fn it_draws_the_best_path() {
+        let input = "#####
+        #..E#
+        #S###
+        #####";
+        let maze = maze::parse(input).unwrap();
+        let frames = frames(&maze).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[0].to_string(), "#####\n#..E#\n#>###\n#####\nScore 0");
+        assert_eq!(
+            frames[1].to_string(),
+            "#####\n#..E#\n#^###\n#####\nScore 1000"
+        );
+        assert_eq!(
+            frames[5].to_string(),
+            "#####\n#OO>#\n#O###\n#####\nScore 2003"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::maze::{moves, walk, Maze};
+use common::Answer;
+use grid::{Direction, Frames};
+use std::{collections::HashSet, iter};
+
+/// The number of tiles on any of the best paths through the maze, if there
+/// is a path at all.
+fn best_path_tiles(maze: &Maze) -> Option<usize> {
+    let start = (maze.start, Direction::Right);
+    let paths = search::dijkstra_all(
+        &start,
+        |state| moves(maze, state),
+        |&(pos, _)| pos == maze.end,
+    );
+
+    paths.map(|(paths, _)| {
+        let tiles = paths.on_any_path().into_iter().map(|(pos, _)| pos);
+        tiles.collect::<HashSet<_>>().len()
+    })
+}
+
+/// The reindeer following one of the best paths, then every tile on any of
+/// the best paths.
+pub fn frames(maze: &Maze) -> Frames {
+    let start = (maze.start, Direction::Right);
+    let Some((paths, score)) = search::dijkstra_all(
+        &start,
+        |state| moves(maze, state),
+        |&(pos, _)| pos == maze.end,
+    ) else {
+        return Box::new(walk(maze.clone(), vec![start]));
+    };
+
+    let tiles = paths
+        .on_any_path()
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>();
+    let end = *paths.ends().next().unwrap();
+    let last = maze.frame(&tiles, end).with_caption(format!(
+        "Score {score}, {} tiles on the best paths",
+        tiles.len()
+    ));
+
+    Box::new(walk(maze.clone(), paths.path()).chain(iter::once(last)))
+}
+
+pub fn solve(maze: &Maze) -> Answer {
+    best_path_tiles(maze).map_or_else(
+        || Answer::Error("no path from the start to the end tile".to_string()),
+        Into::into,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze;
+
+    #[test]
+    fn it_solves() {
+        let input = "###############
+        #.......#....E#
+        #.#.###.#.###.#
+        #.....#.#...#.#
+        #.###.#####.#.#
+        #.#.#.......#.#
+        #.#.#####.###.#
+        #...........#.#
+        ###.#.#####.#.#
+        #...#.....#.#.#
+        #.#.#.###.#.#.#
+        #.....#...#.#.#
+        #.###.#.#.#.#.#
+        #S..#.....#...#
+        ###############"
+            .to_string();
+        let maze = maze::parse(&input).unwrap();
+        assert_eq!(best_path_tiles(&maze), Some(45));
+
+        let input = "#################
+        #...#...#...#..E#
+        #.#.#.#.#.#.#.#.#
+        #.#.#.#...#...#.#
+        #.#.#.#.###.#.#.#
+        #...#.#.#.....#.#
+        #.#.#.#.#.#####.#
+        #.#...#.#.#.....#
+        #.#.#####.#.###.#
+        #.#.#.......#...#
+        #.#.###.#####.###
+        #.#.#...#.....#.#
+        #.#.#.#####.###.#
+        #.#.#.........#.#
+        #.#.#.#########.#
+        #S#.............#
+        #################"
+            .to_string();
+        let maze = maze::parse(&input).unwrap();
+        assert_eq!(best_path_tiles(&maze), Some(64));
+    }
+
+    #[test]
+    fn it_reports_a_walled_off_end() {
+        let maze = maze::parse("#####\n#S#E#\n#####").unwrap();
+
+        assert_eq!(
+            solve(&maze),
+            Answer::Error("no path from the start to the end tile".to_string())
+        );
+    }
+
+    #[test]
+    fn it_draws_every_best_tile() {
+        let input = "#####
+        #...#
+        #.#.#
+        #S.E#
+        #####";
+        let maze = maze::parse(input).unwrap();
+        let last = frames(&maze).last().unwrap();
+
+        assert_eq!(
+            last.to_string(),
+            "#####\n#...#\n#.#.#\n#OO>#\n#####\nScore 2, 3 tiles on the best paths"
+        );
+    }
+}
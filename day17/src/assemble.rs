@@ -0,0 +1,264 @@
+use common::{parse, ParseError};
+use std::collections::HashMap;
+
+/// How an instruction reads its operand.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Operand {
+    Literal,
+    Combo,
+    /// A literal address, or a label.
+    Jump,
+    /// `bxc` reads no operand, so it can be left out, but one can be given
+    /// after `B^C` or on its own.
+    Ignored,
+}
+
+fn opcode(mnemonic: &str) -> Option<(u8, Operand)> {
+    match mnemonic {
+        "adv" => Some((0, Operand::Combo)),
+        "bxl" => Some((1, Operand::Literal)),
+        "bst" => Some((2, Operand::Combo)),
+        "jnz" => Some((3, Operand::Jump)),
+        "bxc" => Some((4, Operand::Ignored)),
+        "out" => Some((5, Operand::Combo)),
+        "bdv" => Some((6, Operand::Combo)),
+        "cdv" => Some((7, Operand::Combo)),
+        _ => None,
+    }
+}
+
+/// One instruction from the source, before its operand is encoded.
+struct Statement<'a> {
+    mnemonic: &'a str,
+    operand: &'a str,
+}
+
+/// Assembles mnemonic source into a program. Instructions are separated by
+/// newlines or `;`, `#` starts a comment, and `name:` labels the next
+/// instruction so `jnz name` can jump to it. Operands can be written as the
+/// disassembler shows them, so `adv A>>B` and `adv B` are the same.
+pub fn assemble(source: &str) -> Result<Vec<u8>, ParseError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+
+    for line in source.lines() {
+        let code = line.split('#').next().unwrap_or_default();
+
+        for statement in code.split(';') {
+            let mut statement = statement.trim();
+
+            if let Some((label, rest)) = statement.split_once(':') {
+                let label = label.trim();
+                if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err(ParseError::at(
+                        source,
+                        statement,
+                        format!("expected a label, found `{label}`"),
+                    ));
+                }
+                if labels.insert(label, statements.len() * 2).is_some() {
+                    return Err(ParseError::at(
+                        source,
+                        label,
+                        format!("label `{label}` is defined twice"),
+                    ));
+                }
+                statement = rest.trim();
+            }
+
+            if statement.is_empty() {
+                continue;
+            }
+
+            let (mnemonic, operand) = statement
+                .split_once(char::is_whitespace)
+                .map_or((statement, ""), |(mnemonic, operand)| {
+                    (mnemonic, operand.trim())
+                });
+            statements.push(Statement { mnemonic, operand });
+        }
+    }
+
+    let mut program = Vec::new();
+    for Statement { mnemonic, operand } in statements {
+        let (opcode, kind) = opcode(mnemonic).ok_or_else(|| {
+            ParseError::at(
+                source,
+                mnemonic,
+                format!("unknown instruction `{mnemonic}`"),
+            )
+        })?;
+
+        if operand.is_empty() && kind != Operand::Ignored {
+            return Err(ParseError::at(
+                source,
+                mnemonic,
+                format!("`{mnemonic}` needs an operand"),
+            ));
+        }
+
+        let operand = match kind {
+            Operand::Literal => literal(source, operand.strip_prefix("B^").unwrap_or(operand))?,
+            Operand::Combo => {
+                let operand = operand.strip_prefix("A>>").unwrap_or(operand);
+                combo(source, operand.strip_suffix("%8").unwrap_or(operand))?
+            }
+            Operand::Jump => match labels.get(operand) {
+                Some(&addr) if addr > 7 => {
+                    return Err(ParseError::at(
+                        source,
+                        operand,
+                        format!("label `{operand}` is at {addr}, too far for a 3-bit jump"),
+                    ))
+                }
+                Some(&addr) => addr as u8,
+                None if operand.starts_with(|c: char| c.is_ascii_digit()) => {
+                    literal(source, operand)?
+                }
+                None => {
+                    return Err(ParseError::at(
+                        source,
+                        operand,
+                        format!("unknown label `{operand}`"),
+                    ))
+                }
+            },
+            Operand::Ignored => match operand.strip_prefix("B^C").unwrap_or(operand).trim() {
+                "" => 0,
+                operand => literal(source, operand)?,
+            },
+        };
+
+        program.extend([opcode, operand]);
+    }
+
+    Ok(program)
+}
+
+fn literal(source: &str, operand: &str) -> Result<u8, ParseError> {
+    match parse::number::<u8>(source, operand)? {
+        n @ 0..=7 => Ok(n),
+        n => Err(ParseError::at(
+            source,
+            operand,
+            format!("expected a 3-bit value, found `{n}`"),
+        )),
+    }
+}
+
+fn combo(source: &str, operand: &str) -> Result<u8, ParseError> {
+    match operand {
+        "A" | "a" => Ok(4),
+        "B" | "b" => Ok(5),
+        "C" | "c" => Ok(6),
+        operand => match literal(source, operand)? {
+            7 => Err(ParseError::at(
+                source,
+                operand,
+                "combo operand 7 is reserved",
+            )),
+            n => Ok(n),
+        },
+    }
+}
+
+/// The program as puzzle input, for `parse` to read back.
+pub fn to_input(program: &[u8], [reg_a, reg_b, reg_c]: [u64; 3]) -> String {
+    let program = program
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("Register A: {reg_a}\nRegister B: {reg_b}\nRegister C: {reg_c}\n\nProgram: {program}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer;
+
+    #[test]
+    fn it_assembles() {
+        assert_eq!(
+            assemble("bst A; bxl 3; cdv B; adv 3\nbxc; bxl 5; out B; jnz 0").unwrap(),
+            vec![2, 4, 1, 3, 7, 5, 0, 3, 4, 0, 1, 5, 5, 5, 3, 0]
+        );
+    }
+
+    #[test]
+    fn it_assembles_labels_and_comments() {
+        let source = "bxl 7      # runs once
+        loop:
+            adv 3
+            out A%8    # as the disassembler writes it
+            jnz loop";
+
+        assert_eq!(assemble(source).unwrap(), vec![1, 7, 0, 3, 5, 4, 3, 2]);
+        assert_eq!(assemble("top: out 1; jnz top").unwrap(), vec![5, 1, 3, 0]);
+    }
+
+    #[test]
+    fn it_reads_back_the_disassembly() {
+        let program = vec![2, 4, 1, 3, 7, 5, 0, 3, 4, 0, 1, 5, 5, 5, 3, 0];
+        let computer = computer::parse(&to_input(&program, [0, 0, 0])).unwrap();
+        let source = computer
+            .disassemble()
+            .iter()
+            .map(|op| op.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(assemble(&source).unwrap(), program);
+    }
+
+    #[test]
+    fn it_keeps_the_operand_bxc_ignores() {
+        let program = vec![4, 5, 4, 0, 5, 5];
+        let computer = computer::parse(&to_input(&program, [0, 0, 0])).unwrap();
+        let source = computer
+            .disassemble()
+            .iter()
+            .map(|op| op.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(source, vec!["bxc B^C 5", "bxc B^C", "out B%8"]);
+        assert_eq!(assemble(&source.join("\n")).unwrap(), program);
+        assert_eq!(assemble("bxc 5; bxc").unwrap(), vec![4, 5, 4, 0]);
+    }
+
+    #[test]
+    fn it_runs_assembled_programs() {
+        let program = assemble("loop: adv 1; out A; jnz loop").unwrap();
+        let mut computer = computer::parse(&to_input(&program, [729, 0, 0])).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(computer.get_output(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn it_rejects_bad_source() {
+        let error = |source| assemble(source).unwrap_err().to_string();
+
+        assert_eq!(error("adv 7"), "1:5: combo operand 7 is reserved");
+        assert_eq!(
+            error("bst A\nbxl 8"),
+            "2:5: expected a 3-bit value, found `8`"
+        );
+        assert_eq!(error("out B; mul 2"), "1:8: unknown instruction `mul`");
+        assert_eq!(error("jnz end"), "1:5: unknown label `end`");
+        assert_eq!(error("out"), "1:1: `out` needs an operand");
+        assert_eq!(
+            error("a: out 1; a: out 2"),
+            "1:11: label `a` is defined twice"
+        );
+        assert_eq!(
+            error("bad label: out 1"),
+            "1:1: expected a label, found `bad label`"
+        );
+        assert_eq!(
+            error("out 1; out 1; out 1; out 1; end: jnz end"),
+            "1:38: label `end` is at 8, too far for a 3-bit jump"
+        );
+    }
+}
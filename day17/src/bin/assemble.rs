@@ -0,0 +1,24 @@
+// Assembles mnemonic source into puzzle input, with `--a <n>` setting
+// register A.
+// To run: `cargo run --bin assemble -- program.asm --a 729 > src/bin/test.txt`
+
+use common::args;
+use day17::assemble;
+use std::{env, fs};
+
+const USAGE: &str = "assemble <source> [--a <n>]";
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = match args::path(&args, "") {
+        "" => args::usage("expected a source file to assemble", USAGE),
+        path => path.to_string(),
+    };
+    let reg_a = args::number(&args, "--a")
+        .unwrap_or_else(|err| args::usage(&err, USAGE))
+        .unwrap_or(0);
+    let source = fs::read_to_string(&path).expect("to read file");
+    let program = assemble::assemble(&source).unwrap_or_else(|err| panic!("{path}:{err}"));
+
+    println!("{}", assemble::to_input(&program, [reg_a, 0, 0]));
+}
@@ -0,0 +1,47 @@
+// Steps through the program interactively, or with `--trace <file>` writes
+// every instruction it runs to the file as JSON lines (`-` for stdout).
+// `--a <n>` starts with register A set to n.
+// To run: `cargo run --bin debug -- src/bin/input.txt --a 117440`
+
+use common::{args, Solution};
+use day17::{
+    debugger::{self, Debugger, Stop},
+    Day17,
+};
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+};
+
+const USAGE: &str = "debug [input] [--a <n>] [--trace <file>]";
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let reg_a = args::number(&args, "--a").unwrap_or_else(|err| args::usage(&err, USAGE));
+    let trace = args::value(&args, "--trace").unwrap_or_else(|err| args::usage(&err, USAGE));
+
+    let input = fs::read_to_string(&path).expect("to read file");
+    let mut computer = Day17::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+    if let Some(reg_a) = reg_a {
+        computer = computer.with_reg_a(reg_a);
+    }
+
+    if let Some(trace) = trace {
+        let mut out: Box<dyn Write> = match trace {
+            "-" => Box::new(io::stdout().lock()),
+            path => Box::new(BufWriter::new(File::create(path).expect("to create file"))),
+        };
+        let (trace, stop) = debugger::trace(&computer);
+        for snapshot in trace {
+            writeln!(out, "{}", snapshot.to_json()).expect("to write trace");
+        }
+        if let Stop::Failed(err) = stop {
+            eprintln!("stopped: {err}");
+        }
+    } else {
+        let mut debugger = Debugger::new(&computer);
+        debugger::repl(&mut debugger, io::stdin().lock(), &mut io::stdout()).expect("to run");
+    }
+}
@@ -0,0 +1,22 @@
+// Prints the program as mnemonics, or as Rust with `--rust`.
+// To run: `cargo run --bin disassemble -- src/bin/input.txt --rust`
+
+use common::{args, Solution};
+use day17::Day17;
+use std::{env, fs};
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let input = fs::read_to_string(&path).expect("to read file");
+    let computer = Day17::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+
+    if args.iter().any(|arg| arg == "--rust") {
+        match computer.to_rust() {
+            Ok(rust) => println!("{rust}"),
+            Err(err) => println!("{path}: {err}"),
+        }
+    } else {
+        println!("{}", computer.listing());
+    }
+}
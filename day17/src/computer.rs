@@ -0,0 +1,778 @@
+use common::{parse, ParseError};
+use std::fmt;
+
+/// The instruction each of the eight opcodes runs.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Instruction {
+    ADV,
+    BXL,
+    BST,
+    JNZ,
+    BXC,
+    OUT,
+    BDV,
+    CDV,
+}
+
+/// The 3-bit computer: three registers, a program and what it has output.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Computer {
+    pub(crate) reg_a: u64,
+    pub(crate) reg_b: u64,
+    pub(crate) reg_c: u64,
+    pub(crate) program: Vec<u8>,
+    pub(crate) ins_ptr: usize,
+    pub(crate) output: Vec<u8>,
+}
+
+/// Why a program stopped before running off the end.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ExecError {
+    /// A combo operand of 7, which is reserved, at this address.
+    ReservedOperand {
+        addr: usize,
+    },
+    /// The program has an odd number of values, so its last opcode has no
+    /// operand.
+    OddLength {
+        len: usize,
+    },
+    StepLimit(usize),
+    OutputLimit(usize),
+    /// The registers and instruction pointer came back to a state they were
+    /// in `period` steps before, so the program never halts.
+    Cycle {
+        ins_ptr: usize,
+        period: usize,
+    },
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExecError::ReservedOperand { addr } => {
+                write!(f, "combo operand 7 is reserved, found at {addr}")
+            }
+            ExecError::OddLength { len } => {
+                write!(
+                    f,
+                    "the program has {len} values, so its last opcode has no operand"
+                )
+            }
+            ExecError::StepLimit(steps) => write!(f, "still running after {steps} steps"),
+            ExecError::OutputLimit(values) => write!(f, "output more than {values} values"),
+            ExecError::Cycle { ins_ptr, period } => {
+                write!(f, "stuck in a loop of {period} steps at {ins_ptr}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+/// How long a program may run before it is stopped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    pub max_steps: usize,
+    pub max_output: usize,
+    /// Stop as soon as the machine is seen to repeat itself.
+    pub detect_cycles: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_steps: 1_000_000,
+            max_output: 10_000,
+            detect_cycles: true,
+        }
+    }
+}
+
+/// Checks each step of a run against its limits. Cycles are found with
+/// Brent's algorithm, which only keeps one earlier state: the one at the last
+/// power of two steps.
+#[derive(Debug, Clone)]
+pub(crate) struct Watchdog {
+    limits: Limits,
+    steps: usize,
+    saved: (u64, u64, u64, usize),
+    power: usize,
+    period: usize,
+}
+
+impl Watchdog {
+    pub(crate) fn new(computer: &Computer, limits: Limits) -> Self {
+        Watchdog {
+            limits,
+            steps: 0,
+            saved: computer.state(),
+            power: 1,
+            period: 0,
+        }
+    }
+
+    /// Called after each step.
+    pub(crate) fn check(&mut self, computer: &Computer) -> Result<(), ExecError> {
+        self.steps += 1;
+        if self.steps > self.limits.max_steps {
+            return Err(ExecError::StepLimit(self.limits.max_steps));
+        }
+        if computer.output.len() > self.limits.max_output {
+            return Err(ExecError::OutputLimit(self.limits.max_output));
+        }
+
+        if self.limits.detect_cycles {
+            let state = computer.state();
+            self.period += 1;
+            if state == self.saved {
+                return Err(ExecError::Cycle {
+                    ins_ptr: computer.ins_ptr,
+                    period: self.period,
+                });
+            }
+            if self.period == self.power {
+                self.saved = state;
+                self.power *= 2;
+                self.period = 0;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Computer {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let mut reg_a = 0;
+        let mut reg_b = 0;
+        let mut reg_c = 0;
+        let mut program = Vec::new();
+
+        for line in input.lines() {
+            // Skip blank lines
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, val) = parse::split_once(input, line, ":")?;
+            let val = val.trim();
+
+            match key.trim() {
+                "Register A" => {
+                    reg_a = parse::number(input, val)?;
+                }
+                "Register B" => {
+                    reg_b = parse::number(input, val)?;
+                }
+                "Register C" => {
+                    reg_c = parse::number(input, val)?;
+                }
+                "Program" => {
+                    program = val
+                        .split(",")
+                        .map(|v| Self::parse_value(input, v.trim()))
+                        .collect::<Result<_, _>>()?;
+                }
+                key => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("unknown key `{key}`, expected a register or `Program`"),
+                    ))
+                }
+            }
+        }
+
+        Ok(Computer {
+            reg_a,
+            reg_b,
+            reg_c,
+            program,
+            ins_ptr: 0,
+            output: Vec::new(),
+        })
+    }
+
+    fn parse_value(input: &str, value: &str) -> Result<u8, ParseError> {
+        match parse::number::<u8>(input, value)? {
+            n @ 0..=7 => Ok(n),
+            n => Err(ParseError::at(
+                input,
+                value,
+                format!("expected a 3-bit value, found `{n}`"),
+            )),
+        }
+    }
+
+    pub(crate) fn get_ins(&self, opcode: u8) -> Instruction {
+        match opcode {
+            0 => Instruction::ADV,
+            1 => Instruction::BXL,
+            2 => Instruction::BST,
+            3 => Instruction::JNZ,
+            4 => Instruction::BXC,
+            5 => Instruction::OUT,
+            6 => Instruction::BDV,
+            7 => Instruction::CDV,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_combo_operand(&self, operand: u8) -> u64 {
+        match operand {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => self.reg_a,
+            5 => self.reg_b,
+            6 => self.reg_c,
+            _ => unreachable!(),
+        }
+    }
+
+    /// A fresh copy of the computer, with register A set to `reg_a`.
+    pub fn with_reg_a(&self, reg_a: u64) -> Self {
+        Computer {
+            reg_a,
+            ins_ptr: 0,
+            output: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Everything that decides what the machine does next.
+    fn state(&self) -> (u64, u64, u64, usize) {
+        (self.reg_a, self.reg_b, self.reg_c, self.ins_ptr)
+    }
+
+    /// The values output so far, separated by commas.
+    pub fn get_output(&self) -> String {
+        self.output
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn dv(&self, operand: u8) -> u64 {
+        let op = self.get_combo_operand(operand);
+
+        // Dividing by 2^op is a shift, and anything shifted by 64 or more is 0
+        u32::try_from(op)
+            .ok()
+            .and_then(|shift| self.reg_a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    fn adv(&mut self, operand: u8) {
+        let result = self.dv(operand);
+        self.reg_a = result;
+    }
+
+    fn bxl(&mut self, operand: u8) {
+        self.reg_b ^= operand as u64;
+    }
+
+    fn bst(&mut self, operand: u8) {
+        let op = self.get_combo_operand(operand);
+        self.reg_b = op % 8;
+    }
+
+    // Returns `true` if instruction pointer jumps
+    fn jnz(&mut self, operand: u8) -> bool {
+        if self.reg_a == 0 {
+            return false;
+        }
+
+        self.ins_ptr = operand as usize;
+        true
+    }
+
+    fn bxc(&mut self) {
+        self.reg_b ^= self.reg_c;
+    }
+
+    fn out(&mut self, operand: u8) {
+        let op = self.get_combo_operand(operand);
+        self.output.push((op % 8) as u8);
+    }
+
+    fn bdv(&mut self, operand: u8) {
+        let result = self.dv(operand);
+        self.reg_b = result;
+    }
+
+    fn cdv(&mut self, operand: u8) {
+        let result = self.dv(operand);
+        self.reg_c = result;
+    }
+
+    /// Runs the instruction at the instruction pointer. Returns `false`,
+    /// without changing anything, once the pointer is past the end.
+    pub fn step(&mut self) -> Result<bool, ExecError> {
+        let Some(&opcode) = self.program.get(self.ins_ptr) else {
+            return Ok(false);
+        };
+        let Some(&operand) = self.program.get(self.ins_ptr + 1) else {
+            return Err(ExecError::OddLength {
+                len: self.program.len(),
+            });
+        };
+
+        let ins = self.get_ins(opcode);
+        let reads_combo = !matches!(ins, Instruction::BXL | Instruction::JNZ | Instruction::BXC);
+        if reads_combo && operand == 7 {
+            return Err(ExecError::ReservedOperand { addr: self.ins_ptr });
+        }
+
+        match ins {
+            Instruction::ADV => {
+                self.adv(operand);
+                self.ins_ptr += 2;
+            }
+            Instruction::BXL => {
+                self.bxl(operand);
+                self.ins_ptr += 2;
+            }
+            Instruction::BST => {
+                self.bst(operand);
+                self.ins_ptr += 2;
+            }
+            Instruction::JNZ => {
+                if !self.jnz(operand) {
+                    self.ins_ptr += 2;
+                }
+            }
+            Instruction::BXC => {
+                self.bxc();
+                self.ins_ptr += 2;
+            }
+            Instruction::OUT => {
+                self.out(operand);
+                self.ins_ptr += 2;
+            }
+            Instruction::BDV => {
+                self.bdv(operand);
+                self.ins_ptr += 2;
+            }
+            Instruction::CDV => {
+                self.cdv(operand);
+                self.ins_ptr += 2;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Runs the program until the instruction pointer moves past its end,
+    /// within the default limits.
+    pub fn run(&mut self) -> Result<(), ExecError> {
+        self.run_with(Limits::default())
+    }
+
+    pub fn run_with(&mut self, limits: Limits) -> Result<(), ExecError> {
+        if !self.program.len().is_multiple_of(2) {
+            return Err(ExecError::OddLength {
+                len: self.program.len(),
+            });
+        }
+
+        let mut watchdog = Watchdog::new(self, limits);
+        while self.step()? {
+            watchdog.check(self)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Computer, ParseError> {
+    Computer::from(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_computer() {
+        let input = "Register A: 729
+        Register B: 1
+        Register C: 2
+
+        Program: 0,1,5,4,3,0"
+            .to_string();
+        let computer = Computer::from(&input).unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 729,
+                reg_b: 1,
+                reg_c: 2,
+                program: vec![0, 1, 5, 4, 3, 0],
+                ins_ptr: 0,
+                output: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_looks_up_instruction() {
+        let computer = Computer::from("").unwrap();
+
+        assert_eq!(computer.get_ins(0), Instruction::ADV);
+        assert_eq!(computer.get_ins(1), Instruction::BXL);
+        assert_eq!(computer.get_ins(2), Instruction::BST);
+        assert_eq!(computer.get_ins(3), Instruction::JNZ);
+        assert_eq!(computer.get_ins(4), Instruction::BXC);
+        assert_eq!(computer.get_ins(5), Instruction::OUT);
+        assert_eq!(computer.get_ins(6), Instruction::BDV);
+        assert_eq!(computer.get_ins(7), Instruction::CDV);
+    }
+
+    #[test]
+    fn it_gets_combo_operands() {
+        let input = "Register A: 729
+        Register B: 111
+        Register C: 222
+
+        Program: 0,1,5,4,3,0"
+            .to_string();
+        let computer = Computer::from(&input).unwrap();
+
+        assert_eq!(computer.get_combo_operand(0), 0);
+        assert_eq!(computer.get_combo_operand(1), 1);
+        assert_eq!(computer.get_combo_operand(2), 2);
+        assert_eq!(computer.get_combo_operand(3), 3);
+        assert_eq!(computer.get_combo_operand(4), 729);
+        assert_eq!(computer.get_combo_operand(5), 111);
+        assert_eq!(computer.get_combo_operand(6), 222);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_for_invalid_opcodes() {
+        let input = "Register A: 729
+        Register B: 111
+        Register C: 222
+
+        Program: 0,1,5,4,3,0"
+            .to_string();
+        let computer = Computer::from(&input).unwrap();
+        computer.get_combo_operand(7);
+    }
+
+    #[test]
+    fn it_runs_adv() {
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,1"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 364,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 1],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,2"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 182,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 2],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,3"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 91,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 3],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,4"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 0,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 4],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,5"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 91,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 5],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,6"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 45,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 6],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+
+        let input = "Register A: 729
+        Register B: 3
+        Register C: 4
+
+        Program: 0,1"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 364,
+                reg_b: 3,
+                reg_c: 4,
+                program: vec![0, 1],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_runs_adv_with_large_operands() {
+        for (reg_b, reg_a) in [(63, 1), (64, 0), (1 << 32, 0), (u64::MAX, 0)] {
+            let input = format!(
+                "Register A: {}
+                Register B: {reg_b}
+                Register C: 0
+
+                Program: 0,5",
+                u64::MAX
+            );
+            let mut computer = Computer::from(&input).unwrap();
+            computer.run().unwrap();
+
+            assert_eq!(computer.reg_a, reg_a, "shifting by {reg_b}");
+        }
+    }
+
+    #[test]
+    fn it_runs_bxl() {
+        let input = "Register A: 0
+        Register B: 721
+        Register C: 4
+
+        Program: 1,4"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 0,
+                reg_b: 725,
+                reg_c: 4,
+                program: vec![1, 4],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_runs_bst() {
+        let input = "Register A: 123
+        Register B: 0
+        Register C: 0
+
+        Program: 2,4"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(
+            computer,
+            Computer {
+                reg_a: 123,
+                reg_b: 3,
+                reg_c: 0,
+                program: vec![2, 4],
+                ins_ptr: 2,
+                output: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_runs() {
+        let input = "Register A: 729
+        Register B: 0
+        Register C: 0
+
+        Program: 0,1,5,4,3,0"
+            .to_string();
+        let mut computer = Computer::from(&input).unwrap();
+        computer.run().unwrap();
+
+        assert_eq!(computer.get_output(), "4,6,3,5,6,3,5,2,1,0".to_string());
+    }
+
+    #[test]
+    fn it_rejects_bad_programs() {
+        let error = |input| Computer::from(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("Register A: 729\nRegister D: 0\n\nProgram: 0,1"),
+            "2:1: unknown key `Register D`, expected a register or `Program`"
+        );
+        assert_eq!(
+            error("Register A: -729\n\nProgram: 0,1"),
+            "1:13: expected a number, found `-729`"
+        );
+        assert_eq!(
+            error("Register A: 729\n\nProgram: 0,1,8"),
+            "3:14: expected a 3-bit value, found `8`"
+        );
+        assert_eq!(error("Program 0,1"), "1:1: expected `:` in `Program 0,1`");
+    }
+
+    #[test]
+    fn it_stops_on_bad_instructions() {
+        let run = |program: &str| parse(program).unwrap().run();
+
+        assert_eq!(
+            run("Program: 0,1,5,7"),
+            Err(ExecError::ReservedOperand { addr: 2 })
+        );
+        assert_eq!(run("Program: 1,7,4,7,3,7"), Ok(()));
+        assert_eq!(run("Program: 0,1,5"), Err(ExecError::OddLength { len: 3 }));
+        assert_eq!(
+            run("Program: 0,1,5").unwrap_err().to_string(),
+            "the program has 3 values, so its last opcode has no operand"
+        );
+    }
+
+    #[test]
+    fn it_detects_cycles() {
+        let mut computer = parse("Register A: 1\n\nProgram: 3,0").unwrap();
+
+        assert_eq!(
+            computer.run(),
+            Err(ExecError::Cycle {
+                ins_ptr: 0,
+                period: 1
+            })
+        );
+
+        let mut computer = parse("Register A: 1\n\nProgram: 1,1,5,5,3,0").unwrap();
+        assert_eq!(
+            computer.run(),
+            Err(ExecError::Cycle {
+                ins_ptr: 2,
+                period: 6
+            })
+        );
+        assert_eq!(&computer.output[..4], &[1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn it_stops_at_its_limits() {
+        let computer = parse("Register A: 1\n\nProgram: 1,1,5,5,3,0").unwrap();
+        let limits = Limits {
+            max_steps: 100,
+            max_output: 10_000,
+            detect_cycles: false,
+        };
+
+        assert_eq!(
+            computer.clone().run_with(limits),
+            Err(ExecError::StepLimit(100))
+        );
+        assert_eq!(
+            computer.clone().run_with(Limits {
+                max_output: 5,
+                ..limits
+            }),
+            Err(ExecError::OutputLimit(5))
+        );
+    }
+}
@@ -0,0 +1,496 @@
+use crate::{
+    computer::{Computer, ExecError, Limits, Watchdog},
+    disassemble::Op,
+};
+use serde_json::json;
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    /// Every comparison with its symbol, longest symbols first so `<=` isn't
+    /// read as `<`.
+    const ALL: [(&'static str, Comparison); 6] = [
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+
+    fn symbol(self) -> &'static str {
+        Comparison::ALL
+            .iter()
+            .find(|(_, cmp)| *cmp == self)
+            .map(|(symbol, _)| *symbol)
+            .unwrap()
+    }
+
+    fn holds(self, lhs: u64, rhs: u64) -> bool {
+        match self {
+            Comparison::Eq => lhs == rhs,
+            Comparison::Ne => lhs != rhs,
+            Comparison::Lt => lhs < rhs,
+            Comparison::Le => lhs <= rhs,
+            Comparison::Gt => lhs > rhs,
+            Comparison::Ge => lhs >= rhs,
+        }
+    }
+}
+
+/// Where the debugger stops: before the instruction at an address runs, or
+/// as soon as a register compares true against a value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Breakpoint {
+    Address(usize),
+    Register(Register, Comparison, u64),
+}
+
+impl Breakpoint {
+    fn is_hit(&self, computer: &Computer) -> bool {
+        match *self {
+            Breakpoint::Address(addr) => computer.ins_ptr == addr,
+            Breakpoint::Register(register, cmp, value) => {
+                let register = match register {
+                    Register::A => computer.reg_a,
+                    Register::B => computer.reg_b,
+                    Register::C => computer.reg_c,
+                };
+                cmp.holds(register, value)
+            }
+        }
+    }
+}
+
+/// Reads an address, e.g. `4`, or a condition on a register, e.g. `A==0`.
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(addr) = s.parse() {
+            return Ok(Breakpoint::Address(addr));
+        }
+
+        let (symbol, cmp) = Comparison::ALL
+            .into_iter()
+            .find(|(symbol, _)| s.contains(symbol))
+            .ok_or_else(|| {
+                format!("expected an address or a condition like `A==0`, found `{s}`")
+            })?;
+        let (register, value) = s.split_once(symbol).unwrap();
+
+        let register = match register.trim() {
+            "A" | "a" => Register::A,
+            "B" | "b" => Register::B,
+            "C" | "c" => Register::C,
+            register => return Err(format!("unknown register `{register}`")),
+        };
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("expected a number, found `{}`", value.trim()))?;
+
+        Ok(Breakpoint::Register(register, cmp, value))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Address(addr) => write!(f, "ip=={addr}"),
+            Breakpoint::Register(register, cmp, value) => {
+                write!(f, "{register:?}{}{value}", cmp.symbol())
+            }
+        }
+    }
+}
+
+/// The machine just after an instruction has run.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Snapshot {
+    /// How many instructions have run, counting this one.
+    pub step: usize,
+    pub op: Op,
+    pub ins_ptr: usize,
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+    pub output: Vec<u8>,
+}
+
+impl Snapshot {
+    /// The snapshot as one line of JSON.
+    pub fn to_json(&self) -> String {
+        json!({
+            "step": self.step,
+            "addr": self.op.addr,
+            "op": self.op.to_string(),
+            "ins_ptr": self.ins_ptr,
+            "a": self.reg_a,
+            "b": self.reg_b,
+            "c": self.reg_c,
+            "output": self.output,
+        })
+        .to_string()
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#{} {:>3}: {:<10} A={} B={} C={} ip={}",
+            self.step,
+            self.op.addr,
+            self.op.to_string(),
+            self.reg_a,
+            self.reg_b,
+            self.reg_c,
+            self.ins_ptr
+        )
+    }
+}
+
+/// Why the debugger stopped running.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Stop {
+    /// The breakpoint at this index was hit.
+    Breakpoint(usize),
+    Halted,
+    Failed(ExecError),
+}
+
+/// Runs a computer one instruction at a time, recording each one.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: Vec<Breakpoint>,
+    trace: Vec<Snapshot>,
+    watchdog: Watchdog,
+}
+
+impl Debugger {
+    pub fn new(computer: &Computer) -> Self {
+        Debugger {
+            computer: computer.clone(),
+            breakpoints: Vec::new(),
+            trace: Vec::new(),
+            watchdog: Watchdog::new(computer, Limits::default()),
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    /// Every instruction run so far, oldest first.
+    pub fn trace(&self) -> &[Snapshot] {
+        &self.trace
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn remove_breakpoint(&mut self, index: usize) -> Option<Breakpoint> {
+        (index < self.breakpoints.len()).then(|| self.breakpoints.remove(index))
+    }
+
+    /// Runs one instruction, within the default limits.
+    pub fn step(&mut self) -> Result<&Snapshot, Stop> {
+        let op = self.computer.op_at(self.computer.ins_ptr);
+        match self.computer.step() {
+            Ok(true) => {}
+            Ok(false) => return Err(Stop::Halted),
+            Err(err) => return Err(Stop::Failed(err)),
+        }
+        // An instruction ran, so there was one to decode
+        let op = op.unwrap();
+
+        self.trace.push(Snapshot {
+            step: self.trace.len() + 1,
+            op,
+            ins_ptr: self.computer.ins_ptr,
+            reg_a: self.computer.reg_a,
+            reg_b: self.computer.reg_b,
+            reg_c: self.computer.reg_c,
+            output: self.computer.output.clone(),
+        });
+        self.watchdog.check(&self.computer).map_err(Stop::Failed)?;
+        Ok(self.trace.last().unwrap())
+    }
+
+    /// Runs until a breakpoint is hit or the program stops. At least one
+    /// instruction is run, so resuming from a breakpoint moves past it.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            if let Err(stop) = self.step() {
+                return stop;
+            }
+
+            let computer = &self.computer;
+            if let Some(i) = self.breakpoints.iter().position(|b| b.is_hit(computer)) {
+                return Stop::Breakpoint(i);
+            }
+        }
+    }
+}
+
+/// Runs the program until it stops, recording every instruction.
+pub fn trace(computer: &Computer) -> (Vec<Snapshot>, Stop) {
+    let mut debugger = Debugger::new(computer);
+    let stop = debugger.resume();
+    (debugger.trace, stop)
+}
+
+fn describe(stop: &Stop) -> String {
+    match stop {
+        Stop::Breakpoint(i) => format!("breakpoint {i}"),
+        Stop::Halted => "halted".to_string(),
+        Stop::Failed(err) => format!("stopped: {err}"),
+    }
+}
+
+const HELP: &str = "commands:
+  s, step [n]     run n instructions (default 1)
+  c, continue     run until a breakpoint or the end
+  b, break <at>   stop at an address, or when a condition like `A==0` holds
+  d, delete <n>   remove breakpoint n
+  i, info         show the registers, output and breakpoints
+  l, list         show the program, marking the next instruction
+  h, help         show this list
+  q, quit";
+
+/// An interactive session reading commands from `input`.
+pub fn repl(
+    debugger: &mut Debugger,
+    mut input: impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(out, "{HELP}")?;
+    let mut line = String::new();
+
+    loop {
+        write!(out, "(day17) ")?;
+        out.flush()?;
+
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+
+        let line = line.trim();
+        let (command, arg) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(command, arg)| (command, arg.trim()));
+
+        match command {
+            "" => {}
+            "s" | "step" => {
+                let Ok(n) = (if arg.is_empty() { Ok(1) } else { arg.parse() }) else {
+                    writeln!(out, "expected a number of steps, found `{arg}`")?;
+                    continue;
+                };
+
+                for _ in 0..n {
+                    match debugger.step() {
+                        Ok(snapshot) => writeln!(out, "{snapshot}")?,
+                        Err(stop) => {
+                            writeln!(out, "{}", describe(&stop))?;
+                            break;
+                        }
+                    }
+                }
+            }
+            "c" | "continue" => match debugger.resume() {
+                Stop::Breakpoint(i) => writeln!(
+                    out,
+                    "breakpoint {i} ({}) hit at ip {}",
+                    debugger.breakpoints[i], debugger.computer.ins_ptr
+                )?,
+                stop => writeln!(out, "{}", describe(&stop))?,
+            },
+            "b" | "break" => match arg.parse() {
+                Ok(breakpoint) => {
+                    debugger.add_breakpoint(breakpoint);
+                    writeln!(
+                        out,
+                        "breakpoint {} set: {breakpoint}",
+                        debugger.breakpoints.len() - 1
+                    )?;
+                }
+                Err(err) => writeln!(out, "{err}")?,
+            },
+            "d" | "delete" => match arg.parse().ok().and_then(|i| debugger.remove_breakpoint(i)) {
+                Some(breakpoint) => writeln!(out, "deleted {breakpoint}")?,
+                None => writeln!(out, "no breakpoint `{arg}`")?,
+            },
+            "i" | "info" => {
+                let computer = &debugger.computer;
+                writeln!(
+                    out,
+                    "A={} B={} C={} ip={}",
+                    computer.reg_a, computer.reg_b, computer.reg_c, computer.ins_ptr
+                )?;
+                writeln!(out, "output: {}", computer.get_output())?;
+                for (i, breakpoint) in debugger.breakpoints.iter().enumerate() {
+                    writeln!(out, "breakpoint {i}: {breakpoint}")?;
+                }
+            }
+            "l" | "list" => {
+                for op in debugger.computer.disassemble() {
+                    let marker = if op.addr == debugger.computer.ins_ptr {
+                        '>'
+                    } else {
+                        ' '
+                    };
+                    writeln!(out, "{marker}{:>3}  {op}", op.addr)?;
+                }
+            }
+            "h" | "help" => writeln!(out, "{HELP}")?,
+            "q" | "quit" => return Ok(()),
+            command => writeln!(out, "unknown command `{command}`, try `help`")?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer;
+
+    fn computer() -> Computer {
+        computer::parse("Register A: 729\n\nProgram: 0,1,5,4,3,0").unwrap()
+    }
+
+    #[test]
+    fn it_parses_breakpoints() {
+        assert_eq!("4".parse(), Ok(Breakpoint::Address(4)));
+        assert_eq!(
+            "A == 0".parse(),
+            Ok(Breakpoint::Register(Register::A, Comparison::Eq, 0))
+        );
+        assert_eq!(
+            "b<=12".parse(),
+            Ok(Breakpoint::Register(Register::B, Comparison::Le, 12))
+        );
+        assert_eq!(
+            "D>1".parse::<Breakpoint>(),
+            Err("unknown register `D`".to_string())
+        );
+        assert_eq!(
+            "A>x".parse::<Breakpoint>(),
+            Err("expected a number, found `x`".to_string())
+        );
+        assert!("next".parse::<Breakpoint>().is_err());
+    }
+
+    #[test]
+    fn it_steps_and_records() {
+        let mut debugger = Debugger::new(&computer());
+
+        let snapshot = debugger.step().unwrap().clone();
+        assert_eq!(snapshot.step, 1);
+        assert_eq!(snapshot.op.to_string(), "adv A>>1");
+        assert_eq!(snapshot.reg_a, 364);
+        assert_eq!(snapshot.ins_ptr, 2);
+
+        debugger.step().unwrap();
+        assert_eq!(debugger.trace()[1].output, vec![4]);
+        assert_eq!(debugger.trace().len(), 2);
+    }
+
+    #[test]
+    fn it_stops_at_breakpoints() {
+        let mut debugger = Debugger::new(&computer());
+        debugger.add_breakpoint("A<10".parse().unwrap());
+        debugger.add_breakpoint("4".parse().unwrap());
+
+        assert_eq!(debugger.resume(), Stop::Breakpoint(1));
+        assert_eq!(debugger.computer().ins_ptr, 4);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(1));
+
+        debugger.remove_breakpoint(1);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(0));
+        assert_eq!(debugger.computer().reg_a, 5);
+
+        debugger.remove_breakpoint(0);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.computer().get_output(), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(debugger.step(), Err(Stop::Halted));
+    }
+
+    #[test]
+    fn it_stops_programs_that_never_halt() {
+        let computer = computer::parse("Register A: 1\n\nProgram: 5,4,3,0").unwrap();
+        let mut debugger = Debugger::new(&computer);
+
+        assert_eq!(
+            debugger.resume(),
+            Stop::Failed(ExecError::Cycle {
+                ins_ptr: 2,
+                period: 2
+            })
+        );
+        assert_eq!(
+            describe(&trace(&computer).1),
+            "stopped: stuck in a loop of 2 steps at 2"
+        );
+    }
+
+    #[test]
+    fn it_traces_as_json_lines() {
+        let (trace, stop) = trace(&computer());
+
+        assert_eq!(stop, Stop::Halted);
+        assert_eq!(trace.len(), 30);
+        assert_eq!(
+            trace[1].to_json(),
+            r#"{"a":364,"addr":2,"b":0,"c":0,"ins_ptr":4,"op":"out A%8","output":[4],"step":2}"#
+        );
+    }
+
+    #[test]
+    fn it_runs_commands() {
+        let mut debugger = Debugger::new(&computer());
+        let mut out = Vec::new();
+        let commands = "step 2\nbreak A==0\nbogus\ncontinue\ninfo\nlist\nq\nstep\n";
+
+        repl(&mut debugger, commands.as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("#2   2: out A%8"));
+        assert!(out.contains("breakpoint 0 set: A==0"));
+        assert!(out.contains("unknown command `bogus`"));
+        assert!(out.contains("breakpoint 0 (A==0) hit at ip 2"));
+        assert!(out.contains("A=0 B=0 C=0 ip=2"));
+        assert!(out.contains(">  2  out A%8"));
+        assert_eq!(debugger.trace().len(), 28);
+    }
+}
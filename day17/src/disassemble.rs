@@ -0,0 +1,394 @@
+use crate::computer::{Computer, Instruction};
+use std::{collections::BTreeSet, fmt};
+
+/// One decoded instruction and the address it starts at.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Op {
+    pub addr: usize,
+    pub instruction: Instruction,
+    pub operand: u8,
+}
+
+/// The register or literal a combo operand reads, as `get_combo_operand`
+/// would resolve it. Operand 7 is reserved and shown as `?`.
+fn combo(operand: u8) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => "?".to_string(),
+    }
+}
+
+/// Why a program can't be written as Rust.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RustError {
+    /// A combo operand of 7, which is reserved, at this address.
+    ReservedOperand { addr: usize },
+    /// The program has an odd number of values, so its last opcode has no
+    /// operand.
+    OddLength { len: usize },
+    /// A jump into the middle of an instruction, which reads its operand as
+    /// an opcode.
+    OddJump { addr: usize, target: usize },
+}
+
+impl fmt::Display for RustError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RustError::ReservedOperand { addr } => {
+                write!(f, "combo operand 7 is reserved, found at {addr}")
+            }
+            RustError::OddLength { len } => {
+                write!(
+                    f,
+                    "the program has {len} values, so its last opcode has no operand"
+                )
+            }
+            RustError::OddJump { addr, target } => {
+                write!(f, "the jump at {addr} goes to odd address {target}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RustError {}
+
+impl Op {
+    pub fn mnemonic(&self) -> &'static str {
+        match self.instruction {
+            Instruction::ADV => "adv",
+            Instruction::BXL => "bxl",
+            Instruction::BST => "bst",
+            Instruction::JNZ => "jnz",
+            Instruction::BXC => "bxc",
+            Instruction::OUT => "out",
+            Instruction::BDV => "bdv",
+            Instruction::CDV => "cdv",
+        }
+    }
+
+    /// The value the instruction works out, e.g. `A>>B` for `adv 5`, with
+    /// the ignored operand of `bxc` after it unless it's 0.
+    fn argument(&self) -> String {
+        match self.instruction {
+            Instruction::ADV | Instruction::BDV | Instruction::CDV => {
+                format!("A>>{}", combo(self.operand))
+            }
+            Instruction::BXL => format!("B^{}", self.operand),
+            Instruction::BST | Instruction::OUT => format!("{}%8", combo(self.operand)),
+            Instruction::JNZ => self.operand.to_string(),
+            // The operand is ignored, but kept so the program assembles back
+            Instruction::BXC if self.operand == 0 => "B^C".to_string(),
+            Instruction::BXC => format!("B^C {}", self.operand),
+        }
+    }
+
+    fn reads_combo(&self) -> bool {
+        !matches!(
+            self.instruction,
+            Instruction::BXL | Instruction::JNZ | Instruction::BXC
+        )
+    }
+
+    /// The instruction as a Rust statement, or `None` for `jnz`, which
+    /// depends on how the program is laid out. `to_rust` checks for reserved
+    /// operands first.
+    fn statement(&self) -> Option<String> {
+        let operand = combo(self.operand).to_lowercase();
+        // A literal shift is always below 64, but a register can be anything
+        let shifted = match self.operand {
+            0..=3 => format!("a >> {operand}"),
+            _ => format!("a.checked_shr({operand}.min(64) as u32).unwrap_or(0)"),
+        };
+
+        Some(match self.instruction {
+            Instruction::ADV if self.operand <= 3 => format!("a >>= {operand};"),
+            Instruction::ADV => format!("a = {shifted};"),
+            Instruction::BXL => format!("b ^= {};", self.operand),
+            Instruction::BST => format!("b = {operand} % 8;"),
+            Instruction::JNZ => return None,
+            Instruction::BXC => "b ^= c;".to_string(),
+            Instruction::OUT => format!("out.push({operand} % 8);"),
+            Instruction::BDV => format!("b = {shifted};"),
+            Instruction::CDV => format!("c = {shifted};"),
+        })
+    }
+}
+
+/// The Rust statement for each instruction except jumps, indented.
+fn statements(ops: &[Op], indent: &str) -> Vec<String> {
+    ops.iter()
+        .filter_map(|op| Some(format!("{indent}{}", op.statement()?)))
+        .collect()
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.mnemonic(), self.argument())
+    }
+}
+
+impl Computer {
+    /// The instruction that starts at `addr`, if there is one.
+    pub fn op_at(&self, addr: usize) -> Option<Op> {
+        let (&opcode, &operand) = (self.program.get(addr)?, self.program.get(addr + 1)?);
+
+        Some(Op {
+            addr,
+            instruction: self.get_ins(opcode),
+            operand,
+        })
+    }
+
+    /// Decodes the program two values at a time. A trailing opcode without
+    /// an operand is left out.
+    pub fn disassemble(&self) -> Vec<Op> {
+        (0..self.program.len())
+            .step_by(2)
+            .filter_map(|addr| self.op_at(addr))
+            .collect()
+    }
+
+    /// The addresses `jnz` can jump to that start an instruction.
+    fn jump_targets(&self, ops: &[Op]) -> BTreeSet<usize> {
+        ops.iter()
+            .filter(|op| op.instruction == Instruction::JNZ)
+            .map(|op| op.operand as usize)
+            .filter(|&target| ops.iter().any(|op| op.addr == target))
+            .collect()
+    }
+
+    /// The program with an address on each line and a label on every
+    /// instruction that is jumped to.
+    pub fn listing(&self) -> String {
+        let ops = self.disassemble();
+        let targets = self.jump_targets(&ops);
+        let mut lines = Vec::new();
+
+        for op in ops.iter() {
+            if targets.contains(&op.addr) {
+                lines.push(format!("L{}:", op.addr));
+            }
+
+            let target = op.operand as usize;
+            if op.instruction == Instruction::JNZ && targets.contains(&target) {
+                lines.push(format!("{:>4}  jnz L{target}", op.addr));
+            } else {
+                lines.push(format!("{:>4}  {op}", op.addr));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Equivalent Rust, as a function from the registers to the output. A
+    /// program that ends by jumping back to an earlier instruction is written
+    /// as a loop, anything else as a match on the instruction pointer.
+    ///
+    /// Programs that would stop with an error, or jump into an operand, have
+    /// no equivalent and are refused.
+    pub fn to_rust(&self) -> Result<String, RustError> {
+        let ops = self.disassemble();
+        if self.program.len() % 2 == 1 {
+            return Err(RustError::OddLength {
+                len: self.program.len(),
+            });
+        }
+        for op in ops.iter() {
+            if op.reads_combo() && op.operand == 7 {
+                return Err(RustError::ReservedOperand { addr: op.addr });
+            }
+            if op.instruction == Instruction::JNZ && op.operand % 2 == 1 {
+                return Err(RustError::OddJump {
+                    addr: op.addr,
+                    target: op.operand as usize,
+                });
+            }
+        }
+        let mut lines = vec![
+            "fn run(mut a: u64, mut b: u64, mut c: u64) -> Vec<u64> {".to_string(),
+            "    let mut out = Vec::new();".to_string(),
+        ];
+
+        let jumps = ops
+            .iter()
+            .filter(|op| op.instruction == Instruction::JNZ)
+            .collect::<Vec<_>>();
+
+        match jumps[..] {
+            [] => lines.extend(statements(&ops, "    ")),
+            [last] if Some(last) == ops.last() && self.jump_targets(&ops).len() == 1 => {
+                let (before, body) = ops.split_at(last.operand as usize / 2);
+
+                lines.extend(statements(before, "    "));
+                lines.push("    loop {".to_string());
+                lines.extend(statements(body, "        "));
+                lines.push("        if a == 0 {".to_string());
+                lines.push("            break;".to_string());
+                lines.push("        }".to_string());
+                lines.push("    }".to_string());
+            }
+            _ => {
+                lines.push("    let mut ip = 0;".to_string());
+                lines.push(format!("    while ip < {} {{", self.program.len()));
+                lines.push("        match ip {".to_string());
+                for op in ops.iter() {
+                    match op.statement() {
+                        Some(statement) => {
+                            lines.push(format!("            {} => {{", op.addr));
+                            lines.push(format!("                {statement}"));
+                            lines.push("                ip += 2;".to_string());
+                            lines.push("            }".to_string());
+                        }
+                        None => lines.push(format!(
+                            "            {} => ip = if a == 0 {{ ip + 2 }} else {{ {} }},",
+                            op.addr, op.operand
+                        )),
+                    }
+                }
+                // Every jump is to an even address, so `ip` always starts an
+                // instruction
+                lines.push("            _ => unreachable!(),".to_string());
+                lines.push("        }".to_string());
+                lines.push("    }".to_string());
+            }
+        }
+
+        lines.push("    out".to_string());
+        lines.push("}".to_string());
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer;
+
+    #[test]
+    fn it_decodes_operands() {
+        let computer = computer::parse("Program: 0,5,1,3,2,4,3,0,4,1,5,5,6,6,7,0").unwrap();
+        let ops = computer
+            .disassemble()
+            .iter()
+            .map(|op| op.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ops,
+            vec![
+                "adv A>>B",
+                "bxl B^3",
+                "bst A%8",
+                "jnz 0",
+                "bxc B^C 1",
+                "out B%8",
+                "bdv A>>C",
+                "cdv A>>0",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_lists_with_labels() {
+        let computer = computer::parse("Program: 0,1,5,4,3,0").unwrap();
+
+        assert_eq!(
+            computer.listing(),
+            "L0:
+   0  adv A>>1
+   2  out A%8
+   4  jnz L0"
+        );
+    }
+
+    #[test]
+    fn it_shows_reserved_operands_and_odd_jumps() {
+        let computer = computer::parse("Program: 5,7,3,1").unwrap();
+
+        assert_eq!(computer.listing(), "   0  out ?%8\n   2  jnz 1");
+    }
+
+    #[test]
+    fn it_writes_a_loop_as_rust() {
+        let computer = computer::parse("Program: 1,7,0,3,5,4,3,2").unwrap();
+
+        assert_eq!(
+            computer.to_rust().unwrap(),
+            "fn run(mut a: u64, mut b: u64, mut c: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    b ^= 7;
+    loop {
+        a >>= 3;
+        out.push(a % 8);
+        if a == 0 {
+            break;
+        }
+    }
+    out
+}"
+        );
+    }
+
+    #[test]
+    fn it_writes_other_jumps_as_a_match() {
+        let computer = computer::parse("Program: 3,4,5,5,0,1").unwrap();
+
+        assert_eq!(
+            computer.to_rust().unwrap(),
+            "fn run(mut a: u64, mut b: u64, mut c: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    let mut ip = 0;
+    while ip < 6 {
+        match ip {
+            0 => ip = if a == 0 { ip + 2 } else { 4 },
+            2 => {
+                out.push(b % 8);
+                ip += 2;
+            }
+            4 => {
+                a >>= 1;
+                ip += 2;
+            }
+            _ => unreachable!(),
+        }
+    }
+    out
+}"
+        );
+    }
+
+    #[test]
+    fn it_guards_shifts_by_registers() {
+        let computer = computer::parse("Program: 0,5,6,4,7,6,5,6").unwrap();
+
+        assert_eq!(
+            computer.to_rust().unwrap(),
+            "fn run(mut a: u64, mut b: u64, mut c: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    a = a.checked_shr(b.min(64) as u32).unwrap_or(0);
+    b = a.checked_shr(a.min(64) as u32).unwrap_or(0);
+    c = a.checked_shr(c.min(64) as u32).unwrap_or(0);
+    out.push(c % 8);
+    out
+}"
+        );
+    }
+
+    #[test]
+    fn it_refuses_programs_without_an_equivalent() {
+        let rust = |program: &str| computer::parse(program).unwrap().to_rust();
+
+        assert_eq!(
+            rust("Program: 1,7,5,7"),
+            Err(RustError::ReservedOperand { addr: 2 })
+        );
+        assert_eq!(
+            rust("Program: 5,4,3,1").unwrap_err().to_string(),
+            "the jump at 2 goes to odd address 1"
+        );
+        assert_eq!(rust("Program: 5,4,3"), Err(RustError::OddLength { len: 3 }));
+    }
+}
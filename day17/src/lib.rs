@@ -0,0 +1,28 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod assemble;
+pub mod computer;
+pub mod debugger;
+pub mod disassemble;
+pub mod part1;
+pub mod part2;
+pub mod reverse;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input<'a> = computer::Computer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        computer::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
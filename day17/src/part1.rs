@@ -0,0 +1,28 @@
+use crate::computer::Computer;
+use common::Answer;
+
+pub fn solve(computer: &Computer) -> Answer {
+    let mut computer = computer.clone();
+    match computer.run() {
+        Ok(()) => computer.get_output().into(),
+        Err(err) => Answer::Error(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer;
+
+    #[test]
+    fn it_outputs_comma_separated_values() {
+        let input = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+        let computer = computer::parse(input).unwrap();
+
+        assert_eq!(solve(&computer), "4,6,3,5,6,3,5,2,1,0".into());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{computer::Computer, reverse};
+use common::Answer;
+
+pub fn solve(computer: &Computer) -> Answer {
+    match reverse::find_quine(computer) {
+        Ok(reg_a) => reg_a.into(),
+        Err(err) => Answer::Error(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer;
+
+    #[test]
+    fn it_finds_a_quine() {
+        let input = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+        let computer = computer::parse(input).unwrap();
+
+        assert_eq!(solve(&computer), 117440.into());
+    }
+}
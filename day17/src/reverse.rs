@@ -0,0 +1,210 @@
+use crate::computer::{Computer, Instruction};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SolveError {
+    /// The program isn't a loop the solver can invert, and why.
+    UnsupportedShape(String),
+    /// No value of register A makes the program output what was asked for.
+    NoSolution,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::UnsupportedShape(reason) => {
+                write!(f, "unsupported program shape: {reason}")
+            }
+            SolveError::NoSolution => write!(f, "no value of register A gives that output"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+fn unsupported(reason: &str) -> SolveError {
+    SolveError::UnsupportedShape(reason.to_string())
+}
+
+/// Checks the program is a loop the solver can invert and returns how many
+/// bits of A each pass through the loop consumes.
+///
+/// The loop has to run from the start of the program to a `jnz 0` at its
+/// end, shift A right by a constant once, output once, and set B and C
+/// before reading them. Then each pass only depends on the value of A when
+/// it starts, so the output of A is the output of its lowest bits followed by
+/// the output of `A >> shift`.
+fn loop_shift(computer: &Computer) -> Result<u64, SolveError> {
+    if !computer.program.len().is_multiple_of(2) {
+        return Err(unsupported("the program has a trailing opcode"));
+    }
+
+    let ops = computer.disassemble();
+    let jumps = ops
+        .iter()
+        .filter(|op| op.instruction == Instruction::JNZ)
+        .collect::<Vec<_>>();
+    if jumps.len() != 1 || Some(jumps[0]) != ops.last() || jumps[0].operand != 0 {
+        return Err(unsupported("it must be one loop ending in `jnz 0`"));
+    }
+
+    let shifts = ops
+        .iter()
+        .filter(|op| op.instruction == Instruction::ADV)
+        .collect::<Vec<_>>();
+    let shift = match shifts[..] {
+        [op] if (1..=3).contains(&op.operand) => op.operand as u64,
+        _ => {
+            return Err(unsupported(
+                "A must be shifted right by 1 to 3 once per loop",
+            ))
+        }
+    };
+
+    let outputs = ops
+        .iter()
+        .filter(|op| op.instruction == Instruction::OUT)
+        .count();
+    if outputs != 1 {
+        return Err(unsupported("the loop must output exactly one value"));
+    }
+
+    // Which of A, B and C have been set so far in this pass
+    let mut set = [true, false, false];
+    for op in ops.iter() {
+        let reads_combo = matches!(
+            op.instruction,
+            Instruction::ADV
+                | Instruction::BST
+                | Instruction::OUT
+                | Instruction::BDV
+                | Instruction::CDV
+        );
+        let combo = match op.operand {
+            4..=6 if reads_combo => Some(op.operand as usize - 4),
+            7 if reads_combo => return Err(unsupported("combo operand 7 is reserved")),
+            _ => None,
+        };
+        let (reads, writes) = match op.instruction {
+            Instruction::ADV => (vec![Some(0), combo], Some(0)),
+            Instruction::BXL => (vec![Some(1)], Some(1)),
+            Instruction::BST => (vec![combo], Some(1)),
+            Instruction::JNZ => (vec![Some(0)], None),
+            Instruction::BXC => (vec![Some(1), Some(2)], Some(1)),
+            Instruction::OUT => (vec![combo], None),
+            Instruction::BDV => (vec![Some(0), combo], Some(1)),
+            Instruction::CDV => (vec![Some(0), combo], Some(2)),
+        };
+
+        if let Some(register) = reads.into_iter().flatten().find(|&r| !set[r]) {
+            let name = ["A", "B", "C"][register];
+            return Err(SolveError::UnsupportedShape(format!(
+                "register {name} is read before it is set, so it carries over between loops"
+            )));
+        }
+        if let Some(register) = writes {
+            set[register] = true;
+        }
+    }
+
+    Ok(shift)
+}
+
+/// The smallest value of register A that makes the program output `output`.
+///
+/// Works backwards from the last value output, which only depends on the
+/// highest bits of A, adding `shift` bits of A for each earlier value and
+/// keeping every candidate that still outputs the end of `output`.
+pub fn find_a(computer: &Computer, output: &[u8]) -> Result<u64, SolveError> {
+    let shift = loop_shift(computer)?;
+    let mut candidates = vec![0];
+
+    for i in (0..output.len()).rev() {
+        candidates = candidates
+            .into_iter()
+            .flat_map(|high: u64| {
+                (0..1 << shift).filter_map(move |low| high.checked_mul(1 << shift).map(|a| a + low))
+            })
+            .filter(|&a| {
+                let mut dut = computer.with_reg_a(a);
+                dut.run().is_ok() && dut.output == output[i..]
+            })
+            .collect();
+    }
+
+    match output {
+        [] => Err(SolveError::NoSolution),
+        _ => candidates.into_iter().min().ok_or(SolveError::NoSolution),
+    }
+}
+
+/// The smallest value of register A that makes the program output itself.
+pub fn find_quine(computer: &Computer) -> Result<u64, SolveError> {
+    find_a(computer, &computer.program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer;
+
+    fn output(computer: &Computer, reg_a: u64) -> Vec<u8> {
+        let mut computer = computer.with_reg_a(reg_a);
+        computer.run().unwrap();
+        computer.output
+    }
+
+    #[test]
+    fn it_finds_a_quine() {
+        let computer = computer::parse("Register A: 2024\n\nProgram: 0,3,5,4,3,0").unwrap();
+
+        assert_eq!(find_quine(&computer), Ok(117440));
+    }
+
+    #[test]
+    fn it_finds_a_for_any_output() {
+        let computer = computer::parse("Program: 2,4,1,3,7,5,0,3,4,1,1,5,5,5,3,0").unwrap();
+
+        let wanted = output(&computer, 12345);
+        let a = find_a(&computer, &wanted).unwrap();
+        assert!(a <= 12345);
+        assert_eq!(output(&computer, a), wanted);
+        assert!((0..a).all(|smaller| output(&computer, smaller) != wanted));
+
+        let a = find_quine(&computer).unwrap();
+        assert_eq!(output(&computer, a), computer.program);
+    }
+
+    #[test]
+    fn it_handles_other_shifts() {
+        let computer = computer::parse("Program: 0,2,5,4,3,0").unwrap();
+        assert_eq!(find_a(&computer, &[5, 1, 0]), Ok(0b10100));
+        assert_eq!(find_a(&computer, &[7, 0]), Err(SolveError::NoSolution));
+    }
+
+    #[test]
+    fn it_rejects_unsupported_shapes() {
+        let error = |program: &str| {
+            let computer = computer::parse(&format!("Program: {program}")).unwrap();
+            find_quine(&computer).unwrap_err().to_string()
+        };
+
+        assert_eq!(
+            error("0,3,5,4,3,2"),
+            "unsupported program shape: it must be one loop ending in `jnz 0`"
+        );
+        assert_eq!(
+            error("0,4,5,4,3,0"),
+            "unsupported program shape: A must be shifted right by 1 to 3 once per loop"
+        );
+        assert_eq!(
+            error("0,3,5,4,5,4,3,0"),
+            "unsupported program shape: the loop must output exactly one value"
+        );
+        assert_eq!(
+            error("1,1,0,3,5,5,3,0"),
+            "unsupported program shape: register B is read before it is set, so it carries \
+             over between loops"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use common::{Answer, ParseError, Solution};
+use grid::Frames;
+
+pub mod memory;
+pub mod part1;
+pub mod part2;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input<'a> = memory::Memory;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        memory::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
+
+/// Draws the bytes falling into memory for `aoc run --visualize`, with the
+/// shortest path once they land for part 1 and after every byte for part 2.
+pub fn frames(part: u8, input: &str) -> Option<Result<Frames, ParseError>> {
+    match part {
+        1 => Some(memory::parse(input).map(|memory| part1::frames(&memory))),
+        2 => Some(memory::parse(input).map(|memory| part2::frames(&memory))),
+        _ => None,
+    }
+}
@@ -0,0 +1,200 @@
+use common::{parse, ParseError};
+use grid::{Cell, Colour, Frame, Grid, Point};
+use std::collections::HashSet;
+
+/// The bytes predicted to fall, in order, and the memory space they corrupt.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Memory {
+    pub(crate) predicted: Vec<Point>,
+    pub(crate) corrupted: Grid<bool>,
+}
+
+impl Memory {
+    fn from(input: &str, small: bool) -> Result<Self, ParseError> {
+        let (n_x, n_y) = if small { (7, 7) } else { (71, 71) };
+
+        let predicted = input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (x, y) = parse::split_once(input, line, ",")?;
+                let (x, y) = (parse::number(input, x)?, parse::number(input, y)?);
+
+                let byte = Point::new(x, y);
+                if !(0..n_x).contains(&x) || !(0..n_y).contains(&y) {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("byte `{line}` is outside the {n_x}x{n_y} memory space"),
+                    ));
+                }
+                Ok(byte)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Memory {
+            predicted,
+            corrupted: Grid::new(n_x as usize, n_y as usize, false),
+        })
+    }
+
+    // Only the first 12 bytes have fallen in the 7x7 example space.
+    pub(crate) fn fallen(&self) -> usize {
+        if self.corrupted.width() == 7 {
+            12
+        } else {
+            1024
+        }
+    }
+
+    /// Corrupts the first `n` bytes to fall.
+    pub(crate) fn corrupt(&mut self, n: usize) {
+        for &byte in self.predicted[0..usize::min(n, self.predicted.len())].iter() {
+            self.corrupted[byte] = true;
+        }
+    }
+
+    fn is_free(&self, point: Point) -> bool {
+        self.corrupted.get(point) == Some(&false)
+    }
+
+    /// Draws the memory space with the corrupted bytes and a path through it.
+    pub fn frame(&self, path: &[Point]) -> Frame {
+        let path = path.iter().collect::<HashSet<_>>();
+
+        self.corrupted.frame(|point, &corrupted| {
+            if corrupted {
+                Cell::new('#', Colour::Red)
+            } else if path.contains(&point) {
+                Cell::new('O', Colour::Green)
+            } else {
+                Cell::new('.', Colour::Grey)
+            }
+        })
+    }
+}
+
+/// A path with the fewest steps from the top left corner to the exit in the
+/// bottom right.
+pub(crate) fn path_to_exit(memory: &Memory) -> Option<Vec<Point>> {
+    let exit = Point::new(
+        memory.corrupted.width() as i32 - 1,
+        memory.corrupted.height() as i32 - 1,
+    );
+    let successors = |&pos: &Point| {
+        memory
+            .corrupted
+            .neighbours(pos)
+            .filter(|&next| memory.is_free(next))
+    };
+
+    search::bfs(&Point::ORIGIN, successors, |&pos| pos == exit)
+}
+
+pub(crate) fn shortest_path(memory: &Memory) -> Option<u32> {
+    path_to_exit(memory).map(|path| path.len() as u32 - 1)
+}
+
+pub fn parse(input: &str) -> Result<Memory, ParseError> {
+    parse_in_space(input, false)
+}
+
+/// Parses the bytes into the 7x7 example memory space if `small` is set,
+/// and the full 71x71 space otherwise.
+pub fn parse_in_space(input: &str, small: bool) -> Result<Memory, ParseError> {
+    Memory::from(input, small)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_small_map() {
+        let input = "5,4
+        1,2
+        0,5
+        2,0"
+        .to_string();
+        let memory_space = Memory::from(&input, true).unwrap();
+
+        assert_eq!(memory_space.corrupted.width(), 7);
+        assert_eq!(memory_space.corrupted.height(), 7);
+        assert_eq!(memory_space.corrupted.find(&true), None);
+        assert_eq!(memory_space.predicted.len(), 4);
+        assert!(memory_space.predicted.contains(&Point::new(5, 4)));
+        assert!(memory_space.predicted.contains(&Point::new(1, 2)));
+        assert!(memory_space.predicted.contains(&Point::new(0, 5)));
+        assert!(memory_space.predicted.contains(&Point::new(2, 0)));
+    }
+
+    #[test]
+    fn it_parses_a_large_map() {
+        let input = "5,4
+        1,2
+        0,5
+        2,0"
+        .to_string();
+        let memory_space = Memory::from(&input, false).unwrap();
+
+        assert_eq!(memory_space.corrupted.width(), 71);
+        assert_eq!(memory_space.corrupted.height(), 71);
+        assert_eq!(memory_space.corrupted.find(&true), None);
+        assert_eq!(memory_space.predicted.len(), 4);
+        assert!(memory_space.predicted.contains(&Point::new(5, 4)));
+        assert!(memory_space.predicted.contains(&Point::new(1, 2)));
+        assert!(memory_space.predicted.contains(&Point::new(0, 5)));
+        assert!(memory_space.predicted.contains(&Point::new(2, 0)));
+    }
+
+    #[test]
+    fn it_corrupts_memory() {
+        let input = "5,4
+        1,2
+        0,5
+        2,0"
+        .to_string();
+        let mut memory_space = Memory::from(&input, true).unwrap();
+        memory_space.corrupt(2);
+
+        assert_eq!(memory_space.corrupted.find_all(&true).count(), 2);
+        assert!(memory_space.corrupted[Point::new(5, 4)]);
+        assert!(memory_space.corrupted[Point::new(1, 2)]);
+    }
+
+    #[test]
+    fn it_finds_shortest_path() {
+        let input = "5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2
+        5,5
+        2,5
+        6,5
+        1,4
+        0,4
+        6,4
+        1,1
+        6,1
+        1,0
+        0,5
+        1,6
+        2,0"
+        .to_string();
+        let mut memory_space = Memory::from(&input, true).unwrap();
+        memory_space.corrupt(12);
+        let num_steps = shortest_path(&memory_space);
+
+        assert_eq!(num_steps, Some(22));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::memory::{path_to_exit, shortest_path, Memory};
+use common::Answer;
+use grid::Frames;
+
+/// The bytes falling one at a time, then the shortest path once they have
+/// all landed.
+pub fn frames(memory: &Memory) -> Frames {
+    let mut memory = memory.clone();
+    let bytes = memory.predicted[..memory.fallen().min(memory.predicted.len())].to_vec();
+    let n_bytes = bytes.len();
+
+    Box::new((0..=n_bytes + 1).map(move |n| {
+        if n == 0 {
+            return memory.frame(&[]).with_caption("0 bytes fallen");
+        }
+        if n <= n_bytes {
+            memory.corrupted[bytes[n - 1]] = true;
+            return memory.frame(&[]).with_caption(format!("{n} bytes fallen"));
+        }
+
+        let path = path_to_exit(&memory).unwrap_or_default();
+        let steps = path.len().saturating_sub(1);
+        memory
+            .frame(&path)
+            .with_caption(format!("{steps} steps to the exit"))
+    }))
+}
+
+pub fn solve(memory: &Memory) -> Answer {
+    let mut memory = memory.clone();
+
+    memory.corrupt(memory.fallen());
+    shortest_path(&memory).map_or_else(
+        || Answer::Error("no path to the exit".to_string()),
+        Into::into,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory;
+    use grid::Point;
+
+    #[test]
+    fn it_counts_the_steps_to_the_exit() {
+        let input = "5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2
+        5,5
+        2,5
+        6,5
+        1,4
+        0,4
+        6,4
+        1,1
+        6,1
+        1,0
+        0,5
+        1,6
+        2,0";
+        let memory_space = memory::parse_in_space(input, true).unwrap();
+
+        assert_eq!(solve(&memory_space), 22.into());
+    }
+
+    #[test]
+    fn it_reports_a_blocked_exit() {
+        let memory_space = memory::parse_in_space("0,1\n1,0", true).unwrap();
+
+        assert_eq!(
+            solve(&memory_space),
+            Answer::Error("no path to the exit".to_string())
+        );
+    }
+
+    #[test]
+    fn it_draws_the_bytes_falling() {
+        let input = "5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2";
+        let memory_space = memory::parse_in_space(input, true).unwrap();
+        let frames = frames(&memory_space).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 14);
+        assert_eq!(frames[1].caption, "1 bytes fallen");
+        assert_eq!(frames[1].cells[Point::new(5, 4)].ch, '#');
+        assert_eq!(
+            frames[13].to_string(),
+            "OO.#OOO
+.O#OO#O
+.OOO#OO
+...#OO#
+..#OO#.
+.#.O#..
+#.#OOOO
+22 steps to the exit"
+        );
+    }
+}
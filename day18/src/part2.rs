@@ -0,0 +1,153 @@
+use crate::memory::{path_to_exit, shortest_path, Memory};
+use common::Answer;
+use grid::{Cell, Colour, Frames};
+use std::iter;
+
+/// The bytes falling one at a time with a shortest path to the exit, until
+/// one cuts the exit off.
+pub fn frames(memory: &Memory) -> Frames {
+    let mut memory = memory.clone();
+    let path = path_to_exit(&memory).unwrap_or_default();
+    let first = memory.frame(&path).with_caption("0 bytes fallen");
+    let mut blocked = false;
+
+    let bytes = memory.predicted.clone().into_iter().enumerate();
+    let falling = bytes.map_while(move |(i, byte)| {
+        if blocked {
+            return None;
+        }
+        memory.corrupted[byte] = true;
+
+        let frame = match path_to_exit(&memory) {
+            Some(path) => memory
+                .frame(&path)
+                .with_caption(format!("{} bytes fallen", i + 1)),
+            None => {
+                blocked = true;
+                let mut frame = memory.frame(&[]);
+                frame.cells[byte] = Cell::new('#', Colour::Yellow);
+                frame.with_caption(format!("{byte} cuts off the exit"))
+            }
+        };
+        Some(frame)
+    });
+
+    Box::new(iter::once(first).chain(falling))
+}
+
+pub fn solve(memory: &Memory) -> Answer {
+    let mut memory = memory.clone();
+
+    // Binary search for the first byte that cuts off the exit, where
+    // `end_idx` is past the end while none has been found
+    let (mut start_idx, mut end_idx) = (0, memory.predicted.len());
+    while start_idx < end_idx {
+        let mid_idx = (start_idx + end_idx) / 2;
+        memory.corrupted.fill(false);
+        memory.corrupt(mid_idx + 1);
+
+        if shortest_path(&memory).is_none() {
+            end_idx = mid_idx;
+        } else {
+            start_idx = mid_idx + 1;
+        }
+    }
+
+    memory.predicted.get(start_idx).map_or_else(
+        || Answer::Error("no byte cuts off the exit".to_string()),
+        |byte| byte.to_string().into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory;
+    use grid::Point;
+
+    #[test]
+    fn it_finds_the_first_blocking_byte() {
+        let input = "5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2
+        5,5
+        2,5
+        6,5
+        1,4
+        0,4
+        6,4
+        1,1
+        6,1
+        1,0
+        0,5
+        1,6
+        2,0";
+        let memory_space = memory::parse_in_space(input, true).unwrap();
+
+        assert_eq!(solve(&memory_space), "6,1".into());
+    }
+
+    #[test]
+    fn it_finds_the_last_byte_when_it_blocks() {
+        let memory_space = memory::parse("70,69\n69,70").unwrap();
+
+        assert_eq!(solve(&memory_space), "69,70".into());
+    }
+
+    #[test]
+    fn it_reports_when_no_byte_blocks() {
+        let memory_space = memory::parse_in_space("5,4\n4,2", true).unwrap();
+
+        assert_eq!(
+            solve(&memory_space),
+            Answer::Error("no byte cuts off the exit".to_string())
+        );
+    }
+
+    #[test]
+    fn it_draws_until_the_exit_is_cut_off() {
+        let input = "5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2
+        5,5
+        2,5
+        6,5
+        1,4
+        0,4
+        6,4
+        1,1
+        6,1
+        1,0
+        0,5
+        1,6
+        2,0";
+        let memory_space = memory::parse_in_space(input, true).unwrap();
+        let frames = frames(&memory_space).collect::<Vec<_>>();
+        let last = frames.last().unwrap();
+
+        assert_eq!(frames.len(), 22);
+        assert_eq!(last.caption, "6,1 cuts off the exit");
+        assert_eq!(last.cells[Point::new(6, 1)], Cell::new('#', Colour::Yellow));
+        assert_eq!(frames[0].cells[Point::new(6, 6)].ch, 'O');
+    }
+}
@@ -0,0 +1,242 @@
+use crate::trie::Trie;
+use num_bigint::BigUint;
+
+/// Every way of making one design from the towels, worked out once from the
+/// end of the design backwards.
+#[derive(Debug, Clone)]
+pub struct Arrangements<'a> {
+    towels: &'a [&'a str],
+    /// The towels that start at each position, with their lengths.
+    fits: Vec<Vec<(usize, usize)>>,
+    /// The number of ways to make the rest of the design from each position,
+    /// with one extra for the empty end.
+    ways: Vec<BigUint>,
+    /// The fewest towels that make the rest of the design from each
+    /// position, if it can be made at all.
+    fewest: Vec<Option<usize>>,
+}
+
+impl<'a> Arrangements<'a> {
+    pub fn new(trie: &Trie, towels: &'a [&'a str], design: &str) -> Self {
+        let fits = (0..design.len())
+            .map(|i| trie.prefixes(&design.as_bytes()[i..]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut ways = vec![BigUint::ZERO; design.len() + 1];
+        let mut fewest = vec![None; design.len() + 1];
+        ways[design.len()] = BigUint::from(1u8);
+        fewest[design.len()] = Some(0);
+
+        for i in (0..design.len()).rev() {
+            for &(_, len) in fits[i].iter() {
+                ways[i] = &ways[i] + &ways[i + len];
+                if let Some(rest) = fewest[i + len] {
+                    fewest[i] = Some(fewest[i].map_or(rest + 1, |best: usize| best.min(rest + 1)));
+                }
+            }
+        }
+
+        Arrangements {
+            towels,
+            fits,
+            ways,
+            fewest,
+        }
+    }
+
+    pub fn possible(&self) -> bool {
+        self.fewest[0].is_some()
+    }
+
+    /// The number of arrangements, or `None` if it doesn't fit in a `u128`.
+    pub fn count(&self) -> Option<u128> {
+        u128::try_from(&self.ways[0]).ok()
+    }
+
+    /// The number of arrangements, however large.
+    pub fn count_big(&self) -> &BigUint {
+        &self.ways[0]
+    }
+
+    /// The towels that continue an arrangement from `i` and still finish
+    /// the design, in the order they were listed.
+    fn choices(&self, i: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut fits = self.fits[i].clone();
+        fits.sort();
+        fits.into_iter()
+            .filter(move |&(_, len)| self.fewest[i + len].is_some())
+    }
+
+    /// Up to `limit` arrangements, in the order of the towel list at each
+    /// step.
+    pub fn list(&self, limit: usize) -> Vec<Vec<&'a str>> {
+        let mut found = Vec::new();
+        self.extend(0, &mut Vec::new(), limit, &mut found);
+        found
+    }
+
+    fn extend(
+        &self,
+        i: usize,
+        towels: &mut Vec<&'a str>,
+        limit: usize,
+        found: &mut Vec<Vec<&'a str>>,
+    ) {
+        if found.len() == limit {
+            return;
+        }
+        if i == self.fits.len() {
+            found.push(towels.clone());
+            return;
+        }
+
+        for (towel, len) in self.choices(i) {
+            towels.push(self.towels[towel]);
+            self.extend(i + len, towels, limit, found);
+            towels.pop();
+        }
+    }
+
+    /// One arrangement chosen uniformly at random, repeatably for the same
+    /// `seed`, or `None` if there isn't one.
+    pub fn sample(&self, seed: u64) -> Option<Vec<&'a str>> {
+        if !self.possible() {
+            return None;
+        }
+
+        // xorshift64, never seeded with 0
+        let mut state = seed | 1;
+        let mut random = |below: &BigUint| {
+            let words = (below.bits() / 64 + 2) as usize;
+            let bits = (0..words)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state
+                })
+                .collect::<Vec<_>>();
+            BigUint::from_slice(
+                &bits
+                    .iter()
+                    .flat_map(|&word| [word as u32, (word >> 32) as u32])
+                    .collect::<Vec<_>>(),
+            ) % below
+        };
+
+        // Pick a number below the count, then find the arrangement with that
+        // index among them
+        let mut index = random(&self.ways[0]);
+        let mut towels = Vec::new();
+        let mut i = 0;
+        while i < self.fits.len() {
+            for (towel, len) in self.choices(i) {
+                if index < self.ways[i + len] {
+                    towels.push(self.towels[towel]);
+                    i += len;
+                    break;
+                }
+                index -= &self.ways[i + len];
+            }
+        }
+
+        Some(towels)
+    }
+
+    /// An arrangement with the fewest towels. Of several as short, the first
+    /// in the order `list` gives them.
+    pub fn fewest_towels(&self) -> Option<Vec<&'a str>> {
+        let mut towels = Vec::new();
+        let mut i = 0;
+
+        while i < self.fits.len() {
+            let left = self.fewest[i]?;
+            let (towel, len) = self
+                .choices(i)
+                .find(|&(_, len)| self.fewest[i + len] == Some(left - 1))?;
+            towels.push(self.towels[towel]);
+            i += len;
+        }
+
+        Some(towels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOWELS: [&str; 8] = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+
+    fn arrange<'a>(towels: &'a [&'a str], design: &str) -> Arrangements<'a> {
+        Arrangements::new(&Trie::new(towels), towels, design)
+    }
+
+    #[test]
+    fn it_counts_arrangements() {
+        let counts = [
+            "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb",
+        ]
+        .map(|design| arrange(&TOWELS, design).count());
+
+        assert_eq!(counts, [2, 1, 4, 6, 0, 1, 2, 0].map(Some));
+        assert!(!arrange(&TOWELS, "ubwu").possible());
+    }
+
+    #[test]
+    fn it_counts_past_u128() {
+        // Every composition of 200 as a sum of 1s and 2s
+        let towels = ["a", "aa"];
+        let arrangements = arrange(&towels, &"a".repeat(200));
+
+        assert_eq!(arrangements.count(), None);
+        assert_eq!(
+            arrangements.count_big().to_string(),
+            "453973694165307953197296969697410619233826"
+        );
+    }
+
+    #[test]
+    fn it_lists_arrangements() {
+        assert_eq!(
+            arrange(&TOWELS, "gbbr").list(10),
+            vec![
+                vec!["g", "b", "b", "r"],
+                vec!["g", "b", "br"],
+                vec!["gb", "b", "r"],
+                vec!["gb", "br"],
+            ]
+        );
+        assert_eq!(arrange(&TOWELS, "rrbgbr").list(2).len(), 2);
+        assert!(arrange(&TOWELS, "ubwu").list(10).is_empty());
+        assert_eq!(arrange(&TOWELS, "").list(10), vec![Vec::<&str>::new()]);
+        assert!(arrange(&TOWELS, "bré").list(10).is_empty());
+    }
+
+    #[test]
+    fn it_samples_arrangements() {
+        let arrangements = arrange(&TOWELS, "rrbgbr");
+        let all = arrangements.list(usize::MAX);
+        let samples = (0..200)
+            .map(|seed| arrangements.sample(seed).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(samples.iter().all(|sample| all.contains(sample)));
+        assert!(all.iter().all(|arrangement| samples.contains(arrangement)));
+        assert_eq!(arrangements.sample(7), arrangements.sample(7));
+        assert_eq!(arrange(&TOWELS, "bbrgwb").sample(1), None);
+    }
+
+    #[test]
+    fn it_finds_the_fewest_towels() {
+        assert_eq!(
+            arrange(&TOWELS, "rrbgbr").fewest_towels(),
+            Some(vec!["r", "rb", "g", "br"])
+        );
+        assert_eq!(
+            arrange(&TOWELS, "bwurrg").fewest_towels(),
+            Some(vec!["bwu", "r", "r", "g"])
+        );
+        assert_eq!(arrange(&TOWELS, "ubwu").fewest_towels(), None);
+    }
+}
@@ -0,0 +1,48 @@
+// Prints how many ways each design can be made and one with the fewest
+// towels. `--list <n>` also prints up to n arrangements of each design, and
+// `--sample <seed>` one picked at random.
+// To run: `cargo run --bin arrangements -- src/bin/input.txt --list 3`
+
+use common::{args, Solution};
+use day19::{arrange::Arrangements, trie::Trie, Day19};
+use std::{env, fs};
+
+const USAGE: &str = "arrangements [input] [--list <n>] [--sample <seed>]";
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let option =
+        |flag| args::number::<u64>(&args, flag).unwrap_or_else(|err| args::usage(&err, USAGE));
+    let list = option("--list");
+    let sample = option("--sample");
+
+    let input = fs::read_to_string(&path).expect("to read file");
+    let (towels, designs) = Day19::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+
+    let trie = Trie::new(&towels);
+    for design in designs {
+        let arrangements = Arrangements::new(&trie, &towels, design);
+        let Some(fewest) = arrangements.fewest_towels() else {
+            println!("{design}: impossible");
+            continue;
+        };
+        println!(
+            "{design}: {} ways, fewest {} towels: {}",
+            arrangements.count_big(),
+            fewest.len(),
+            fewest.join(", ")
+        );
+
+        if let Some(limit) = list {
+            for towels in arrangements.list(limit as usize) {
+                println!("  {}", towels.join(", "));
+            }
+        }
+        if let Some(seed) = sample {
+            if let Some(towels) = arrangements.sample(seed) {
+                println!("  sampled: {}", towels.join(", "));
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// Prints how far towels get into each impossible design, and which towels
+// the others can make. `--json` prints the same report as JSON.
+// To run: `cargo run --bin report -- src/bin/input.txt --json`
+
+use common::{args, Solution};
+use day19::{report::Report, Day19};
+use std::{env, fs};
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let input = fs::read_to_string(&path).expect("to read file");
+    let onsen = Day19::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+    let report = Report::new(&onsen);
+
+    if args.iter().any(|arg| arg == "--json") {
+        println!("{}", report.to_json());
+    } else {
+        println!("{report}");
+    }
+}
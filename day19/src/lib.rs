@@ -0,0 +1,27 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod arrange;
+pub mod onsen;
+pub mod part1;
+pub mod part2;
+pub mod report;
+pub mod trie;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input<'a> = onsen::Onsen<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        onsen::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,78 @@
+use common::ParseError;
+
+/// The available towel patterns and the designs to make from them.
+pub type Onsen<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+pub fn parse(input: &str) -> Result<Onsen<'_>, ParseError> {
+    let (towels, designs) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::eof(input, "a blank line before the designs"))?;
+
+    let towels = towels
+        .trim()
+        .split(",")
+        .map(|towel| {
+            let towel = towel.trim();
+            if towel.is_empty() || !towel.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(ParseError::at(
+                    input,
+                    towel,
+                    format!("expected a towel pattern, found `{towel}`"),
+                ));
+            }
+            Ok(towel)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let patterns = designs
+        .lines()
+        .map(str::trim)
+        .filter(|design| !design.is_empty())
+        .map(|design| {
+            if !design.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(ParseError::at(
+                    input,
+                    design,
+                    format!("expected a design, found `{design}`"),
+                ));
+            }
+            Ok(design)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((towels, patterns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_the_input() {
+        let input = "r, wr, b, g, bwu, rb, gb, br
+
+        brwrr
+        gbbr"
+            .to_string();
+        let (towels, patterns) = parse(&input).unwrap();
+
+        assert_eq!(towels, vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]);
+        assert_eq!(patterns, vec!["brwrr", "gbbr"]);
+    }
+
+    #[test]
+    fn it_rejects_bad_towels() {
+        assert_eq!(
+            parse("r, wr").unwrap_err().to_string(),
+            "1:6: expected a blank line before the designs, found end of input"
+        );
+        assert_eq!(
+            parse("r, W1\n\nrr").unwrap_err().to_string(),
+            "1:4: expected a towel pattern, found `W1`"
+        );
+        assert_eq!(
+            parse("r, wr\n\nrr\nbré").unwrap_err().to_string(),
+            "4:1: expected a design, found `bré`"
+        );
+    }
+}
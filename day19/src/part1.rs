@@ -0,0 +1,48 @@
+use crate::{arrange::Arrangements, onsen::Onsen, trie::Trie};
+use common::Answer;
+
+fn validate_design(trie: &Trie, towels: &[&str], pattern: &str) -> bool {
+    Arrangements::new(trie, towels, pattern).possible()
+}
+
+pub fn solve((towels, patterns): &Onsen) -> Answer {
+    let trie = Trie::new(towels);
+
+    patterns
+        .iter()
+        .filter(|pattern| validate_design(&trie, towels, pattern))
+        .count()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onsen;
+
+    #[test]
+    fn it_solves_a_pattern() {
+        let input = "r, wr, b, g, bwu, rb, gb, br
+
+        brwrr
+        bggr
+        gbbr
+        rrbgbr
+        ubwu
+        bwurrg
+        brgr
+        bbrgwb"
+            .to_string();
+        let (towels, patterns) = onsen::parse(&input).unwrap();
+        let trie = Trie::new(&towels);
+
+        assert!(validate_design(&trie, &towels, patterns[0]));
+        assert!(validate_design(&trie, &towels, patterns[1]));
+        assert!(validate_design(&trie, &towels, patterns[2]));
+        assert!(validate_design(&trie, &towels, patterns[3]));
+        assert!(!validate_design(&trie, &towels, patterns[4]));
+        assert!(validate_design(&trie, &towels, patterns[5]));
+        assert!(validate_design(&trie, &towels, patterns[6]));
+        assert!(!validate_design(&trie, &towels, patterns[7]));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{arrange::Arrangements, onsen::Onsen, trie::Trie};
+use common::Answer;
+use num_bigint::BigUint;
+
+fn validate_design(trie: &Trie, towels: &[&str], pattern: &str) -> BigUint {
+    Arrangements::new(trie, towels, pattern).count_big().clone()
+}
+
+pub fn solve((towels, patterns): &Onsen) -> Answer {
+    let trie = Trie::new(towels);
+    let total = patterns
+        .iter()
+        .map(|pattern| validate_design(&trie, towels, pattern))
+        .sum::<BigUint>();
+
+    u64::try_from(&total).map_or_else(|_| total.to_string().into(), Answer::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onsen;
+
+    #[test]
+    fn it_counts_arrangements() {
+        let input = "r, wr, b, g, bwu, rb, gb, br
+
+        brwrr
+        bggr
+        gbbr
+        rrbgbr
+        ubwu
+        bwurrg
+        brgr
+        bbrgwb"
+            .to_string();
+        let (towels, patterns) = onsen::parse(&input).unwrap();
+        let trie = Trie::new(&towels);
+
+        let counts = patterns
+            .iter()
+            .map(|pattern| validate_design(&trie, &towels, pattern))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [2u8, 1, 4, 6, 0, 1, 2, 0].map(BigUint::from));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{onsen::Onsen, trie::Trie};
+use serde_json::json;
+use std::fmt;
+
+/// A design no arrangement of towels makes, and how far they get.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Impossible<'a> {
+    pub design: &'a str,
+    /// The length of the longest prefix some arrangement makes.
+    pub built: usize,
+    /// The first position an arrangement reaches where no towel fits.
+    pub stuck_at: usize,
+}
+
+/// The impossible designs and redundant towels of a puzzle input.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Report<'a> {
+    pub designs: usize,
+    pub impossible: Vec<Impossible<'a>>,
+    pub redundant: Vec<&'a str>,
+}
+
+/// The positions of `design` that arrangements of towels shorter than
+/// `longest` can reach, starting from 0.
+fn reachable(trie: &Trie, design: &str, longest: usize) -> Vec<bool> {
+    let mut reached = vec![false; design.len() + 1];
+    reached[0] = true;
+
+    for i in 0..design.len() {
+        if reached[i] {
+            for (_, len) in trie.prefixes(&design.as_bytes()[i..]) {
+                if len < longest {
+                    reached[i + len] = true;
+                }
+            }
+        }
+    }
+
+    reached
+}
+
+/// How far towels get into `design`, or `None` if they make all of it.
+///
+/// Towels are ASCII, so every position an arrangement reaches is on a
+/// character boundary, even in a design that isn't.
+pub fn diagnose<'a>(trie: &Trie, design: &'a str) -> Option<Impossible<'a>> {
+    let reached = reachable(trie, design, usize::MAX);
+    if reached[design.len()] {
+        return None;
+    }
+
+    let built = reached.iter().rposition(|&reached| reached)?;
+    let stuck_at = (0..design.len())
+        .find(|&i| reached[i] && trie.prefixes(&design.as_bytes()[i..]).next().is_none())
+        .unwrap_or(built);
+
+    Some(Impossible {
+        design,
+        built,
+        stuck_at,
+    })
+}
+
+/// The towels that other towels can make, in the order they were listed. A
+/// towel listed twice is redundant the second time.
+///
+/// Removing all of them at once makes no design impossible, since each one
+/// is made of shorter towels that are either kept or made of shorter ones
+/// again.
+pub fn redundant_towels<'a>(towels: &[&'a str]) -> Vec<&'a str> {
+    let trie = Trie::new(towels);
+
+    towels
+        .iter()
+        .enumerate()
+        .filter(|&(i, towel)| {
+            let first = trie
+                .prefixes(towel.as_bytes())
+                .last()
+                .map(|(first, _)| first);
+            first != Some(i) || reachable(&trie, towel, towel.len())[towel.len()]
+        })
+        .map(|(_, &towel)| towel)
+        .collect()
+}
+
+impl<'a> Report<'a> {
+    pub fn new((towels, designs): &Onsen<'a>) -> Self {
+        let trie = Trie::new(towels);
+
+        Report {
+            designs: designs.len(),
+            impossible: designs
+                .iter()
+                .filter_map(|design| diagnose(&trie, design))
+                .collect(),
+            redundant: redundant_towels(towels),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let impossible = self
+            .impossible
+            .iter()
+            .map(|impossible| {
+                json!({
+                    "design": impossible.design,
+                    "built": &impossible.design[..impossible.built],
+                    "stuck_at": impossible.stuck_at,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::to_string_pretty(&json!({
+            "designs": self.designs,
+            "impossible": impossible,
+            "redundant": self.redundant,
+        }))
+        .unwrap()
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} of {} designs are impossible",
+            self.impossible.len(),
+            self.designs
+        )?;
+        for impossible in self.impossible.iter() {
+            let (built, rest) = impossible.design.split_at(impossible.built);
+            writeln!(
+                f,
+                "  {built}|{rest}: no towel fits at {} (`{}`)",
+                impossible.stuck_at,
+                &impossible.design[impossible.stuck_at..]
+            )?;
+        }
+
+        write!(f, "{} redundant towels", self.redundant.len())?;
+        if !self.redundant.is_empty() {
+            write!(f, ": {}", self.redundant.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onsen;
+
+    const EXAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn it_diagnoses_impossible_designs() {
+        let report = Report::new(&onsen::parse(EXAMPLE).unwrap());
+
+        assert_eq!(
+            report.impossible,
+            vec![
+                Impossible {
+                    design: "ubwu",
+                    built: 0,
+                    stuck_at: 0
+                },
+                Impossible {
+                    design: "bbrgwb",
+                    built: 4,
+                    stuck_at: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_finds_where_an_arrangement_first_gets_stuck() {
+        let trie = Trie::new(&["a", "ab"]);
+
+        assert_eq!(
+            diagnose(&trie, "abx"),
+            Some(Impossible {
+                design: "abx",
+                built: 2,
+                stuck_at: 1
+            })
+        );
+        assert_eq!(diagnose(&trie, "aab"), None);
+        assert_eq!(
+            diagnose(&trie, "abéa"),
+            Some(Impossible {
+                design: "abéa",
+                built: 2,
+                stuck_at: 1
+            })
+        );
+        assert_eq!(
+            Report::new(&(vec!["a", "ab"], vec!["abéa"])).to_string(),
+            "1 of 1 designs are impossible\n  ab|éa: no towel fits at 1 (`béa`)\n0 redundant towels"
+        );
+    }
+
+    #[test]
+    fn it_finds_redundant_towels() {
+        assert_eq!(
+            redundant_towels(&["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]),
+            vec!["rb", "gb", "br"]
+        );
+        assert_eq!(
+            redundant_towels(&["ab", "a", "b", "a", "abab"]),
+            vec!["ab", "a", "abab"]
+        );
+    }
+
+    #[test]
+    fn it_writes_text_and_json() {
+        let report = Report::new(&onsen::parse(EXAMPLE).unwrap());
+
+        assert_eq!(
+            report.to_string(),
+            "2 of 8 designs are impossible
+  |ubwu: no towel fits at 0 (`ubwu`)
+  bbrg|wb: no towel fits at 4 (`wb`)
+3 redundant towels: rb, gb, br"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["designs"], 8);
+        assert_eq!(json["impossible"][1]["built"], "bbrg");
+        assert_eq!(json["impossible"][1]["stuck_at"], 4);
+        assert_eq!(json["redundant"], json!(["rb", "gb", "br"]));
+    }
+}
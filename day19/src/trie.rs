@@ -0,0 +1,91 @@
+/// The towels as a trie over their colours, so every towel that starts at a
+/// position of a design is found in one walk no longer than the longest
+/// towel.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Trie {
+    /// The child of each node for each colour, where 0 means none, since the
+    /// root is never a child.
+    children: Vec<[u32; 26]>,
+    /// The towel that ends at each node, if any.
+    towel: Vec<Option<usize>>,
+}
+
+impl Trie {
+    /// Indexes the towels, which are lowercase ASCII as `onsen::parse`
+    /// checks. A towel listed twice keeps its first index.
+    pub fn new(towels: &[&str]) -> Self {
+        let mut trie = Trie {
+            children: vec![[0; 26]],
+            towel: vec![None],
+        };
+
+        for (i, towel) in towels.iter().enumerate() {
+            let mut node = 0;
+            for colour in towel.bytes() {
+                let next = trie.children[node][(colour - b'a') as usize];
+                node = if next == 0 {
+                    trie.children.push([0; 26]);
+                    trie.towel.push(None);
+                    let child = trie.children.len() - 1;
+                    trie.children[node][(colour - b'a') as usize] = child as u32;
+                    child
+                } else {
+                    next as usize
+                };
+            }
+            trie.towel[node].get_or_insert(i);
+        }
+
+        trie
+    }
+
+    /// The index and length of every towel that `design` starts with,
+    /// shortest first. Takes bytes so a design can be walked from any
+    /// offset, even one inside a character no towel has.
+    pub fn prefixes<'a>(&'a self, design: &'a [u8]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let mut node = 0;
+
+        design
+            .iter()
+            .map_while(move |&colour| {
+                let next = *self.children[node].get(colour.wrapping_sub(b'a') as usize)?;
+                node = next as usize;
+                (next != 0).then_some(node)
+            })
+            .enumerate()
+            .filter_map(|(i, node)| Some((self.towel[node]?, i + 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_every_towel_at_the_start() {
+        let trie = Trie::new(&["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]);
+
+        assert_eq!(
+            trie.prefixes(b"brwrr").collect::<Vec<_>>(),
+            vec![(2, 1), (7, 2)]
+        );
+        assert_eq!(
+            trie.prefixes(b"bwurrg").collect::<Vec<_>>(),
+            vec![(2, 1), (4, 3)]
+        );
+        assert_eq!(trie.prefixes(b"ubwu").count(), 0);
+        assert_eq!(trie.prefixes(b"").count(), 0);
+    }
+
+    #[test]
+    fn it_ignores_colours_it_has_never_seen() {
+        let trie = Trie::new(&["ab", "a", "ab"]);
+
+        assert_eq!(
+            trie.prefixes(b"abc").collect::<Vec<_>>(),
+            vec![(1, 1), (0, 2)]
+        );
+        assert_eq!(trie.prefixes(b"A").count(), 0);
+        assert_eq!(trie.prefixes("é".as_bytes()).count(), 0);
+    }
+}
@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod racetrack;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input<'a> = racetrack::Maze;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        racetrack::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
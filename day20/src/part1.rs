@@ -0,0 +1,74 @@
+use crate::racetrack::Maze;
+use common::Answer;
+use grid::{Direction, Point};
+
+fn find_cheats(maze: &Maze) -> (usize, Vec<Vec<Point>>) {
+    let track = maze.track();
+
+    // For each position along the optimal path, look for adjacent walls and if the other side of
+    // the wall is along the path. If so, jump.
+    let mut cheats: Vec<Vec<Point>> = Vec::new();
+
+    for (idx, &point) in track.iter().enumerate() {
+        for direction in Direction::ALL {
+            let wall = point.step(direction);
+            let cheat = wall.step(direction);
+
+            if maze.walls.get(wall) != Some(&true) {
+                continue;
+            }
+
+            if let Some(cheat_idx) = track.iter().position(|&p| p == cheat) {
+                if cheat_idx > idx {
+                    let mut shortcut = track.to_vec();
+                    shortcut.drain(idx..cheat_idx);
+
+                    cheats.push(shortcut);
+                }
+            }
+        }
+    }
+
+    (track.len() - 1, cheats)
+}
+
+pub fn solve(maze: &Maze) -> Answer {
+    let savings = 100;
+
+    let (optimal, cheats) = find_cheats(maze);
+    cheats
+        .iter()
+        .filter(|path| optimal.saturating_sub(path.len()) >= savings)
+        .count()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::racetrack;
+
+    #[test]
+    fn it_finds_cheats_through_one_wall() {
+        let input = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"
+            .to_string();
+        let maze = racetrack::parse(&input).unwrap();
+        let (_, cheats) = find_cheats(&maze);
+
+        assert_eq!(cheats.len(), 44);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::racetrack::Maze;
+use common::Answer;
+use itertools::Itertools;
+
+fn count_cheats(maze: &Maze, min_savings: usize) -> usize {
+    maze.track()
+        .iter()
+        .enumerate()
+        .tuple_combinations()
+        .filter_map(|((start_cost, start_pos), (end_cost, end_pos))| {
+            let distance = start_pos.manhattan(*end_pos) as usize;
+            if distance <= 20 {
+                return Some(end_cost - distance - start_cost);
+            }
+            None
+        })
+        .filter(|savings| *savings >= min_savings)
+        .count()
+}
+
+pub fn solve(maze: &Maze) -> Answer {
+    count_cheats(maze, 100).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::racetrack;
+
+    #[test]
+    fn it_counts_cheats_up_to_twenty_picoseconds() {
+        let input = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"
+            .to_string();
+        let maze = racetrack::parse(&input).unwrap();
+
+        assert_eq!(count_cheats(&maze, 50), 285);
+    }
+}
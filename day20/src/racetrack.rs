@@ -0,0 +1,109 @@
+use common::ParseError;
+use grid::{Grid, Point};
+
+/// The racetrack, with its start and end tiles and the track between them.
+#[derive(Debug, PartialEq)]
+pub struct Maze {
+    start: Point,
+    end: Point,
+    pub(crate) walls: Grid<bool>,
+    track: Vec<Point>,
+}
+
+impl Maze {
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, |_, ch| match ch {
+            'S' | 'E' | '#' | '.' => Ok(ch),
+            _ => Err(format!("unexpected `{ch}` on the racetrack")),
+        })?;
+
+        let start = tiles
+            .find(&'S')
+            .ok_or_else(|| ParseError::eof(input, "a start tile (`S`)"))?;
+        let end = tiles
+            .find(&'E')
+            .ok_or_else(|| ParseError::eof(input, "an end tile (`E`)"))?;
+
+        let walls = tiles.map(|&ch| ch == '#');
+        let track = find_track(&walls, start, end).ok_or_else(|| {
+            let start = input.find('S').unwrap_or_default();
+            ParseError::at(input, &input[start..], "no track from `S` to `E`")
+        })?;
+
+        Ok(Maze {
+            start,
+            end,
+            walls,
+            track,
+        })
+    }
+
+    /// Every tile along the track from the start to the end, in order.
+    pub fn track(&self) -> &[Point] {
+        &self.track
+    }
+}
+
+fn find_track(walls: &Grid<bool>, start: Point, end: Point) -> Option<Vec<Point>> {
+    let successors = |&point: &Point| -> Vec<(Point, usize)> {
+        walls
+            .neighbours(point)
+            .filter(|&next| !walls[next])
+            .map(|next| (next, 1))
+            .collect()
+    };
+
+    search::dijkstra(&start, successors, |&p| p == end).map(|(track, _)| track)
+}
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    Maze::from(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_the_racetrack() {
+        let input = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"
+            .to_string();
+        let maze = Maze::from(&input).unwrap();
+
+        assert_eq!(maze.walls.width(), 15);
+        assert_eq!(maze.walls.height(), 15);
+        assert_eq!(maze.start, Point::new(1, 3));
+        assert_eq!(maze.end, Point::new(5, 7));
+        assert_eq!(maze.walls.find_all(&true).count(), 140);
+        assert!(maze.walls[Point::new(0, 0)]);
+        assert!(maze.walls[Point::new(14, 14)]);
+        assert!(maze.walls[Point::new(4, 1)]);
+        assert!(maze.walls[Point::new(2, 3)]);
+        assert!(maze.walls[Point::new(6, 7)]);
+        assert!(maze.walls[Point::new(4, 13)]);
+
+        assert_eq!(maze.track().len(), 85);
+    }
+
+    #[test]
+    fn it_rejects_a_racetrack_without_a_track() {
+        assert_eq!(
+            Maze::from("#####\n#S#E#\n#####").unwrap_err().to_string(),
+            "2:2: no track from `S` to `E`"
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use common::ParseError;
+use std::{collections::BTreeMap, hash::Hash};
+
+trait Robot {
+    fn next_move(&self, end: &Self) -> Vec<(Self, Arrow)>
+    where
+        Self: Sized;
+}
+
+/// A key on the door's numeric keypad.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub enum Keypad {
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    A,
+}
+
+impl Keypad {
+    fn parse(ch: char) -> Option<Self> {
+        Some(match ch {
+            '0' => Keypad::Zero,
+            '1' => Keypad::One,
+            '2' => Keypad::Two,
+            '3' => Keypad::Three,
+            '4' => Keypad::Four,
+            '5' => Keypad::Five,
+            '6' => Keypad::Six,
+            '7' => Keypad::Seven,
+            '8' => Keypad::Eight,
+            '9' => Keypad::Nine,
+            'A' => Keypad::A,
+            _ => return None,
+        })
+    }
+
+    fn numeric(code: &[Keypad]) -> usize {
+        let mut result = 0;
+
+        for digit in code {
+            match digit {
+                Keypad::Zero => result *= 10,
+                Keypad::One => result = result * 10 + 1,
+                Keypad::Two => result = result * 10 + 2,
+                Keypad::Three => result = result * 10 + 3,
+                Keypad::Four => result = result * 10 + 4,
+                Keypad::Five => result = result * 10 + 5,
+                Keypad::Six => result = result * 10 + 6,
+                Keypad::Seven => result = result * 10 + 7,
+                Keypad::Eight => result = result * 10 + 8,
+                Keypad::Nine => result = result * 10 + 9,
+                Keypad::A => break,
+            }
+        }
+        result
+    }
+}
+
+impl Robot for Keypad {
+    fn next_move(&self, end: &Self) -> Vec<(Self, Arrow)>
+    where
+        Self: Sized,
+    {
+        if self != end {
+            match self {
+                Keypad::Zero => vec![(Self::A, Arrow::Right), (Self::Two, Arrow::Up)],
+                Keypad::One => vec![(Self::Four, Arrow::Up), (Self::Two, Arrow::Right)],
+                Keypad::Two => vec![
+                    (Self::One, Arrow::Left),
+                    (Self::Zero, Arrow::Down),
+                    (Self::Three, Arrow::Right),
+                    (Self::Five, Arrow::Up),
+                ],
+                Keypad::Three => vec![
+                    (Self::Two, Arrow::Left),
+                    (Self::A, Arrow::Down),
+                    (Self::Six, Arrow::Up),
+                ],
+                Keypad::Four => vec![
+                    (Self::One, Arrow::Down),
+                    (Self::Five, Arrow::Right),
+                    (Self::Seven, Arrow::Up),
+                ],
+                Keypad::Five => vec![
+                    (Self::Four, Arrow::Left),
+                    (Self::Two, Arrow::Down),
+                    (Self::Six, Arrow::Right),
+                    (Self::Eight, Arrow::Up),
+                ],
+                Keypad::Six => vec![
+                    (Self::Five, Arrow::Left),
+                    (Self::Three, Arrow::Down),
+                    (Self::Nine, Arrow::Up),
+                ],
+                Keypad::Seven => vec![(Self::Four, Arrow::Down), (Self::Eight, Arrow::Right)],
+                Keypad::Eight => vec![
+                    (Self::Seven, Arrow::Left),
+                    (Self::Five, Arrow::Down),
+                    (Self::Nine, Arrow::Right),
+                ],
+                Keypad::Nine => vec![(Self::Eight, Arrow::Left), (Self::Six, Arrow::Down)],
+                Keypad::A => vec![(Self::Zero, Arrow::Left), (Self::Three, Arrow::Up)],
+            }
+        } else {
+            vec![(*self, Arrow::A)]
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+enum Arrow {
+    Up,
+    Down,
+    Left,
+    Right,
+    A,
+}
+
+impl Robot for Arrow {
+    fn next_move(&self, end: &Self) -> Vec<(Self, Arrow)>
+    where
+        Self: Sized,
+    {
+        if self != end {
+            match self {
+                Arrow::Up => vec![(Self::Down, Arrow::Down), (Self::A, Arrow::Right)],
+                Arrow::Down => vec![
+                    (Self::Up, Arrow::Up),
+                    (Self::Left, Arrow::Left),
+                    (Self::Right, Arrow::Right),
+                ],
+                Arrow::Left => vec![(Self::Down, Arrow::Right)],
+                Arrow::Right => vec![(Self::Down, Arrow::Left), (Self::A, Arrow::Up)],
+                Arrow::A => vec![(Self::Right, Arrow::Down), (Self::Up, Arrow::Left)],
+            }
+        } else {
+            vec![(*self, Arrow::A)]
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Keypad>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.trim()
+                .chars()
+                .enumerate()
+                .map(|(col, ch)| {
+                    Keypad::parse(ch).ok_or_else(|| {
+                        ParseError::new(
+                            row + 1,
+                            col + 1,
+                            format!("expected a digit or `A`, found `{ch}`"),
+                        )
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The fewest presses on the outermost keypad that move the robot at `depth`
+/// from `current_robot` to `end` and press it.
+fn min_presses<R: Robot + Eq + Hash + Clone>(
+    current_robot: R,
+    depth: usize,
+    end: R,
+    cache: &mut BTreeMap<(usize, Arrow, Arrow), usize>,
+) -> usize {
+    if depth == 0 {
+        return 0;
+    }
+
+    let start = (current_robot, Arrow::A);
+    let target = (end.clone(), Arrow::A);
+    let successors = |(robot, pressed): &(R, Arrow)| {
+        robot
+            .next_move(&end)
+            .into_iter()
+            .map(|next| {
+                let cache_key = (depth - 1, *pressed, next.1);
+                let cost = if let Some(&cost) = cache.get(&cache_key) {
+                    cost
+                } else {
+                    let mut cost = min_presses(*pressed, depth - 1, next.1, cache);
+                    if !matches!(next.1, Arrow::A) {
+                        cost += 1;
+                    }
+                    cache.insert(cache_key, cost);
+                    cost
+                };
+                (next, cost)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let (_, cost) =
+        search::dijkstra(&start, successors, |state| *state == target).expect("No Path found");
+    cost
+}
+
+fn shortest_path(code: &[Keypad], n_robots: usize) -> usize {
+    let mut current = Keypad::A;
+    let mut cache = BTreeMap::new();
+
+    code.iter()
+        .map(|next| {
+            min_presses(
+                std::mem::replace(&mut current, *next),
+                n_robots + 1,
+                *next,
+                &mut cache,
+            ) + 1
+        })
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Keypad>>, ParseError> {
+    parse_input(input)
+}
+
+/// The sum over the codes of the code's numeric part times the fewest
+/// presses needed to type it through `n_robots` directional keypads.
+pub fn complexity(codes: &[Vec<Keypad>], n_robots: usize) -> usize {
+    codes
+        .iter()
+        .map(|code| {
+            let num = Keypad::numeric(code);
+            let path = shortest_path(code, n_robots);
+            num * path
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_the_input() {
+        let input = "029A
+        980A
+        179A
+        456A
+        379A"
+            .to_string();
+        let codes = parse_input(&input).unwrap();
+
+        assert_eq!(codes.len(), 5);
+        assert_eq!(
+            codes[0],
+            vec![Keypad::Zero, Keypad::Two, Keypad::Nine, Keypad::A]
+        );
+        assert_eq!(
+            codes[4],
+            vec![Keypad::Three, Keypad::Seven, Keypad::Nine, Keypad::A]
+        );
+    }
+
+    #[test]
+    fn it_get_numeric_value() {
+        assert_eq!(
+            Keypad::numeric(&[Keypad::Zero, Keypad::Two, Keypad::Nine, Keypad::A]),
+            29
+        );
+        assert_eq!(
+            Keypad::numeric(&[Keypad::Nine, Keypad::Eight, Keypad::Zero, Keypad::A]),
+            980
+        );
+        assert_eq!(
+            Keypad::numeric(&[Keypad::One, Keypad::Seven, Keypad::Nine, Keypad::A]),
+            179
+        );
+        assert_eq!(
+            Keypad::numeric(&[Keypad::Four, Keypad::Five, Keypad::Six, Keypad::A]),
+            456
+        );
+        assert_eq!(
+            Keypad::numeric(&[Keypad::Three, Keypad::Seven, Keypad::Nine, Keypad::A]),
+            379
+        );
+        assert_eq!(
+            Keypad::numeric(&[Keypad::Zero, Keypad::Zero, Keypad::Zero, Keypad::A]),
+            0
+        );
+    }
+
+    #[test]
+    fn it_gets_shortest_path() {
+        let input = "029A
+        980A
+        179A
+        456A
+        379A"
+            .to_string();
+        let codes = parse_input(&input).unwrap();
+
+        assert_eq!(shortest_path(&codes[0], 2), 68);
+        assert_eq!(shortest_path(&codes[1], 2), 60);
+        assert_eq!(shortest_path(&codes[2], 2), 68);
+        assert_eq!(shortest_path(&codes[3], 2), 64);
+        assert_eq!(shortest_path(&codes[4], 2), 64);
+        assert_eq!(complexity(&codes, 2), 126384);
+    }
+}
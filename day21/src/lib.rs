@@ -0,0 +1,24 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod keypad;
+pub mod part1;
+pub mod part2;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input<'a> = Vec<Vec<keypad::Keypad>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        keypad::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
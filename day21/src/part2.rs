@@ -0,0 +1,6 @@
+use crate::keypad::{complexity, Keypad};
+use common::Answer;
+
+pub fn solve(codes: &[Vec<Keypad>]) -> Answer {
+    complexity(codes, 25).into()
+}
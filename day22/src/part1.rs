@@ -0,0 +1,39 @@
+use crate::secret::calculate_secret;
+use common::Answer;
+
+fn sum_secrets(start: &[u64]) -> u64 {
+    let mut result = 0;
+
+    for n in start {
+        let mut next = *n;
+        for _ in 0..2000 {
+            next = calculate_secret(next);
+        }
+        result += next;
+    }
+
+    result
+}
+
+pub fn solve(secrets: &[u64]) -> Answer {
+    sum_secrets(secrets).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secret;
+
+    #[test]
+    fn it_solves() {
+        let input = "1
+        10
+        100
+        2024"
+            .to_string();
+        let start = secret::parse(&input).unwrap();
+
+        let result = sum_secrets(&start);
+        assert_eq!(result, 37327623);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::secret::calculate_secret;
+use common::Answer;
+use std::collections::{HashMap, HashSet};
+
+fn most_bananas(start: &[u64]) -> i32 {
+    let mut windows: HashMap<(i32, i32, i32, i32), i32> = HashMap::new();
+
+    for secret in start {
+        let mut seen: HashSet<(i32, i32, i32, i32)> = HashSet::new();
+        let mut prices: Vec<i32> = Vec::new();
+        let mut deltas: Vec<i32> = Vec::new();
+        let mut next = *secret;
+
+        prices.push(next as i32 % 10);
+        for idx in 0..2000 {
+            next = calculate_secret(next);
+            prices.push(next as i32 % 10);
+            deltas.push(next as i32 % 10 - prices[idx]);
+        }
+
+        for (idx, window) in deltas.windows(4).enumerate() {
+            if !seen.contains(&(window[0], window[1], window[2], window[3])) {
+                seen.insert((window[0], window[1], window[2], window[3]));
+                *windows
+                    .entry((window[0], window[1], window[2], window[3]))
+                    .or_insert(0) += prices[idx + 4];
+            }
+        }
+    }
+
+    windows.values().copied().max().unwrap_or(0)
+}
+
+pub fn solve(secrets: &[u64]) -> Answer {
+    most_bananas(secrets).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secret;
+
+    #[test]
+    fn it_solves() {
+        let input = "1
+        2
+        3
+        2024"
+            .to_string();
+        let start = secret::parse(&input).unwrap();
+
+        let result = most_bananas(&start);
+        assert_eq!(result, 23);
+    }
+
+    #[test]
+    fn it_sells_nothing_without_buyers() {
+        assert_eq!(most_bananas(&[]), 0);
+    }
+}
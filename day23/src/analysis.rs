@@ -0,0 +1,209 @@
+use crate::graph::Graph;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl<'a> Graph<'a> {
+    /// The number of links, counting each once.
+    pub fn edge_count(&self) -> usize {
+        self.nodes().map(|node| self.degree(node)).sum::<usize>() / 2
+    }
+
+    /// The groups of nodes that can reach each other, each sorted, largest
+    /// first.
+    pub fn components(&self) -> Vec<Vec<&'a str>> {
+        let mut seen = BTreeSet::new();
+        let mut components = Vec::new();
+
+        for start in self.nodes() {
+            if !seen.insert(start) {
+                continue;
+            }
+
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                for next in self.neighbours(node) {
+                    if seen.insert(next) {
+                        component.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        components
+    }
+
+    /// How many nodes have each degree.
+    pub fn degree_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for node in self.nodes() {
+            *histogram.entry(self.degree(node)).or_default() += 1;
+        }
+
+        histogram
+    }
+
+    /// The number of triangles each node is part of.
+    pub fn triangles(&self) -> BTreeMap<&'a str, usize> {
+        self.nodes()
+            .map(|node| {
+                let neighbours = self.neighbours(node).collect::<Vec<_>>();
+                let count = neighbours
+                    .iter()
+                    .enumerate()
+                    .map(|(i, a)| {
+                        neighbours[i + 1..]
+                            .iter()
+                            .filter(|b| self.connected(a, b))
+                            .count()
+                    })
+                    .sum();
+                (node, count)
+            })
+            .collect()
+    }
+
+    /// The core number of each node: the largest `k` for which it is in the
+    /// k-core, the part of the graph left after repeatedly removing every
+    /// node with fewer than `k` neighbours.
+    pub fn core_numbers(&self) -> BTreeMap<&'a str, usize> {
+        let mut core = 0;
+
+        self.peel()
+            .into_iter()
+            .map(|(node, degree)| {
+                core = core.max(degree);
+                (node, core)
+            })
+            .collect()
+    }
+
+    /// The nodes of the k-core, sorted.
+    pub fn k_core(&self, k: usize) -> Vec<&'a str> {
+        self.core_numbers()
+            .into_iter()
+            .filter(|&(_, core)| core >= k)
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    /// The fewest hops from `from` to `to`, including both ends. Of several
+    /// paths as short, the one through the earliest nodes in sorted order.
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<Vec<&'a str>> {
+        let from = self.nodes().find(|&node| node == from)?;
+
+        search::bfs(&from, |&node| self.neighbours(node), |&node| node == to)
+    }
+
+    /// The graph in Graphviz format, with the `highlighted` nodes filled in.
+    pub fn to_dot(&self, highlighted: &[&str]) -> String {
+        let mut lines = vec!["graph network {".to_string()];
+
+        for node in self.nodes() {
+            if highlighted.contains(&node) {
+                lines.push(format!(
+                    "    \"{node}\" [style=filled, fillcolor=\"#ffb703\"];"
+                ));
+            } else {
+                lines.push(format!("    \"{node}\";"));
+            }
+        }
+        for a in self.nodes() {
+            for b in self.neighbours(a).filter(|&b| a < b) {
+                lines.push(format!("    \"{a}\" -- \"{b}\";"));
+            }
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graph::Graph, network};
+    use std::collections::BTreeMap;
+
+    // Two triangles joined at `c`, a tail off `e`, and a separate pair
+    const NETWORK: &str = "a-b\nb-c\nc-a\nc-d\nd-e\ne-c\ne-f\nx-y";
+
+    #[test]
+    fn it_finds_components() {
+        let network = network::parse(NETWORK).unwrap();
+        let graph = Graph::new(&network);
+
+        assert_eq!(graph.edge_count(), 8);
+        assert_eq!(
+            graph.components(),
+            vec![vec!["a", "b", "c", "d", "e", "f"], vec!["x", "y"]]
+        );
+    }
+
+    #[test]
+    fn it_counts_degrees_and_triangles() {
+        let network = network::parse(NETWORK).unwrap();
+        let graph = Graph::new(&network);
+
+        assert_eq!(
+            graph.degree_histogram(),
+            BTreeMap::from([(1, 3), (2, 3), (3, 1), (4, 1)])
+        );
+        assert_eq!(
+            graph.triangles(),
+            BTreeMap::from([
+                ("a", 1),
+                ("b", 1),
+                ("c", 2),
+                ("d", 1),
+                ("e", 1),
+                ("f", 0),
+                ("x", 0),
+                ("y", 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_decomposes_into_cores() {
+        let network = network::parse(NETWORK).unwrap();
+        let graph = Graph::new(&network);
+
+        assert_eq!(graph.core_numbers()["f"], 1);
+        assert_eq!(graph.core_numbers()["c"], 2);
+        assert_eq!(graph.k_core(2), vec!["a", "b", "c", "d", "e"]);
+        assert!(graph.k_core(3).is_empty());
+    }
+
+    #[test]
+    fn it_finds_shortest_paths() {
+        let network = network::parse(NETWORK).unwrap();
+        let graph = Graph::new(&network);
+
+        assert_eq!(
+            graph.shortest_path("a", "f"),
+            Some(vec!["a", "c", "e", "f"])
+        );
+        assert_eq!(graph.shortest_path("a", "a"), Some(vec!["a"]));
+        assert_eq!(graph.shortest_path("a", "x"), None);
+        assert_eq!(graph.shortest_path("q", "a"), None);
+    }
+
+    #[test]
+    fn it_writes_dot() {
+        let network = network::parse("a-b\nb-c").unwrap();
+
+        assert_eq!(
+            Graph::new(&network).to_dot(&["b"]),
+            "graph network {
+    \"a\";
+    \"b\" [style=filled, fillcolor=\"#ffb703\"];
+    \"c\";
+    \"a\" -- \"b\";
+    \"b\" -- \"c\";
+}"
+        );
+    }
+}
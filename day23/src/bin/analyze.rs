@@ -0,0 +1,69 @@
+// Prints a summary of the network: its components, degrees, triangles and
+// cores. `--path <from> <to>` prints the fewest hops between two computers
+// instead, and `--dot` the network as a Graphviz graph with the largest
+// clique filled in.
+// To run: `cargo run --bin analyze -- src/bin/input.txt --path kh wq`
+
+use common::{args, Solution};
+use day23::{graph::Graph, Day23};
+use std::{env, fs};
+
+const USAGE: &str = "analyze [input] [--path <from> <to>] [--dot]";
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let hops = args
+        .iter()
+        .position(|arg| arg == "--path")
+        .map(|i| match &args[i + 1..] {
+            [from, to, ..] if !from.starts_with("--") && !to.starts_with("--") => (from, to),
+            _ => args::usage("--path needs two computers", USAGE),
+        });
+
+    let input = fs::read_to_string(&path).expect("to read file");
+    let network = Day23::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+    let graph = Graph::new(&network);
+
+    if let Some((from, to)) = hops {
+        match graph.shortest_path(from, to) {
+            Some(hops) => println!("{} hops: {}", hops.len() - 1, hops.join(" -> ")),
+            None => println!("no path from {from} to {to}"),
+        }
+        return;
+    }
+
+    if args.iter().any(|arg| arg == "--dot") {
+        println!("{}", graph.to_dot(&graph.maximum_clique()));
+        return;
+    }
+
+    let components = graph.components();
+    println!(
+        "{} computers, {} links, {} components (largest has {})",
+        graph.nodes().count(),
+        graph.edge_count(),
+        components.len(),
+        components.first().map_or(0, |c| c.len())
+    );
+
+    println!("\ndegree  computers");
+    for (degree, count) in graph.degree_histogram() {
+        println!("{degree:>6}  {count}");
+    }
+
+    let mut triangles = graph.triangles().into_iter().collect::<Vec<_>>();
+    triangles.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    println!("\nmost triangles");
+    for (node, count) in triangles.iter().take(5) {
+        println!("{node:>6}  {count}");
+    }
+
+    let (_, degeneracy) = graph.degeneracy_order();
+    let core = graph.k_core(degeneracy);
+    println!(
+        "\n{degeneracy}-core ({} computers): {}",
+        core.len(),
+        core.join(",")
+    );
+}
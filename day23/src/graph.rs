@@ -0,0 +1,308 @@
+use crate::network::Network;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+};
+
+type Nodes<'a> = BTreeSet<&'a str>;
+
+/// The network as sorted adjacency sets, so every query visits computers in
+/// the same order and gives the same answer each run.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Graph<'a> {
+    adjacent: BTreeMap<&'a str, Nodes<'a>>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new(network: &Network<'a>) -> Self {
+        let mut adjacent: BTreeMap<&str, Nodes> = BTreeMap::new();
+        for &(a, b) in network.iter() {
+            adjacent.entry(a).or_default().insert(b);
+            adjacent.entry(b).or_default().insert(a);
+        }
+
+        Graph { adjacent }
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.adjacent.keys().copied()
+    }
+
+    pub fn neighbours(&self, node: &str) -> impl Iterator<Item = &'a str> + '_ {
+        self.adjacent.get(node).into_iter().flatten().copied()
+    }
+
+    pub fn degree(&self, node: &str) -> usize {
+        self.adjacent.get(node).map_or(0, |nodes| nodes.len())
+    }
+
+    pub fn connected(&self, a: &str, b: &str) -> bool {
+        self.adjacent.get(a).is_some_and(|nodes| nodes.contains(b))
+    }
+
+    /// Removes the node with the fewest remaining neighbours until none are
+    /// left, returning each node with how many neighbours it had left.
+    pub(crate) fn peel(&self) -> Vec<(&'a str, usize)> {
+        let mut degrees = self
+            .adjacent
+            .iter()
+            .map(|(&node, nodes)| (node, nodes.len()))
+            .collect::<BTreeMap<_, _>>();
+        let mut queue = degrees
+            .iter()
+            .map(|(&node, &degree)| (degree, node))
+            .collect::<BTreeSet<_>>();
+        let mut removed = Vec::with_capacity(degrees.len());
+
+        while let Some((degree, node)) = queue.pop_first() {
+            degrees.remove(node);
+            removed.push((node, degree));
+
+            for next in self.neighbours(node) {
+                if let Some(degree) = degrees.get_mut(next) {
+                    queue.remove(&(*degree, next));
+                    *degree -= 1;
+                    queue.insert((*degree, next));
+                }
+            }
+        }
+
+        removed
+    }
+
+    /// The nodes in the order `peel` removes them, and the most neighbours
+    /// any of them had left when removed, which is the graph's degeneracy.
+    pub fn degeneracy_order(&self) -> (Vec<&'a str>, usize) {
+        let removed = self.peel();
+        let degeneracy = removed.iter().map(|&(_, degree)| degree).max();
+
+        (
+            removed.into_iter().map(|(node, _)| node).collect(),
+            degeneracy.unwrap_or(0),
+        )
+    }
+
+    /// Every clique that can't be grown by another node, each sorted, in
+    /// sorted order.
+    ///
+    /// Bron–Kerbosch, starting from each node in degeneracy order with only
+    /// its later neighbours as candidates, and pivoting on the node that
+    /// rules out the most candidates below that.
+    pub fn maximal_cliques(&self) -> Vec<Vec<&'a str>> {
+        let (order, _) = self.degeneracy_order();
+        let mut cliques = Vec::new();
+        let mut earlier = Nodes::new();
+
+        for node in order {
+            let neighbours = &self.adjacent[node];
+            let candidates = neighbours.difference(&earlier).copied().collect();
+            let excluded = neighbours.intersection(&earlier).copied().collect();
+            self.bron_kerbosch(&mut vec![node], candidates, excluded, &mut cliques);
+            earlier.insert(node);
+        }
+
+        for clique in cliques.iter_mut() {
+            clique.sort();
+        }
+        cliques.sort();
+        cliques
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<&'a str>,
+        mut candidates: Nodes<'a>,
+        mut excluded: Nodes<'a>,
+        cliques: &mut Vec<Vec<&'a str>>,
+    ) {
+        let Some(pivot) = candidates.union(&excluded).copied().max_by_key(|&node| {
+            (
+                self.adjacent[node].intersection(&candidates).count(),
+                std::cmp::Reverse(node),
+            )
+        }) else {
+            cliques.push(clique.clone());
+            return;
+        };
+
+        let pivot_neighbours = &self.adjacent[pivot];
+        let branches = candidates
+            .difference(pivot_neighbours)
+            .copied()
+            .collect::<Vec<_>>();
+        for node in branches {
+            let neighbours = &self.adjacent[node];
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours).copied().collect(),
+                excluded.intersection(neighbours).copied().collect(),
+                cliques,
+            );
+            clique.pop();
+            candidates.remove(node);
+            excluded.insert(node);
+        }
+    }
+
+    /// The largest clique, sorted. Of several the same size, the first in
+    /// sorted order.
+    pub fn maximum_clique(&self) -> Vec<&'a str> {
+        self.maximal_cliques()
+            .into_iter()
+            .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+            .unwrap_or_default()
+    }
+
+    /// Every clique of exactly `size` nodes with at least one node that
+    /// `wanted` accepts, each sorted, in sorted order.
+    pub fn cliques_containing(
+        &self,
+        size: usize,
+        wanted: impl Fn(&str) -> bool,
+    ) -> Vec<Vec<&'a str>> {
+        let mut cliques = Vec::new();
+        if size == 0 {
+            return cliques;
+        }
+
+        for node in self.nodes() {
+            let later = self.adjacent[node]
+                .range::<&str, _>((Bound::Excluded(node), Bound::Unbounded))
+                .copied()
+                .collect();
+            self.grow(&mut vec![node], later, size, &mut cliques);
+        }
+
+        cliques.retain(|clique| clique.iter().any(|&node| wanted(node)));
+        cliques
+    }
+
+    /// Extends `clique` in increasing order with nodes from `candidates`, so
+    /// each clique is only found once.
+    fn grow(
+        &self,
+        clique: &mut Vec<&'a str>,
+        candidates: Nodes<'a>,
+        size: usize,
+        cliques: &mut Vec<Vec<&'a str>>,
+    ) {
+        if clique.len() == size {
+            cliques.push(clique.clone());
+            return;
+        }
+
+        for &node in candidates.iter() {
+            let later = candidates
+                .range::<&str, _>((Bound::Excluded(node), Bound::Unbounded))
+                .filter(|&&next| self.connected(node, next))
+                .copied()
+                .collect();
+            clique.push(node);
+            self.grow(clique, later, size, cliques);
+            clique.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network;
+
+    const EXAMPLE: &str = "kh-tc\nqp-kh\nde-cg\nka-co\nyn-aq\nqp-ub\ncg-tb\nvc-aq\ntb-ka\nwh-tc
+yn-cg\nkh-ub\nta-co\nde-co\ntc-td\ntb-wq\nwh-td\nta-ka\ntd-qp\naq-cg\nwq-ub\nub-vc\nde-ta
+wq-aq\nwq-vc\nwh-yn\nka-de\nkh-ta\nco-tc\nwh-qp\ntb-vc\ntd-yn";
+
+    #[test]
+    fn it_finds_the_maximum_clique() {
+        let network = network::parse(EXAMPLE).unwrap();
+        let graph = Graph::new(&network);
+
+        assert_eq!(graph.maximum_clique(), vec!["co", "de", "ka", "ta"]);
+    }
+
+    #[test]
+    fn it_breaks_ties_in_sorted_order() {
+        let network = network::parse("d-e\ne-f\nf-d\na-b\nb-c\nc-a\nx-y").unwrap();
+        let graph = Graph::new(&network);
+
+        assert_eq!(
+            graph.maximal_cliques(),
+            vec![vec!["a", "b", "c"], vec!["d", "e", "f"], vec!["x", "y"]]
+        );
+        assert_eq!(graph.maximum_clique(), vec!["a", "b", "c"]);
+        assert_eq!(Graph::default().maximum_clique(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn it_orders_by_degeneracy() {
+        // A triangle with a tail
+        let network = network::parse("a-b\nb-c\nc-a\nc-d\nd-e").unwrap();
+        let (order, degeneracy) = Graph::new(&network).degeneracy_order();
+
+        assert_eq!(order, vec!["e", "d", "a", "b", "c"]);
+        assert_eq!(degeneracy, 2);
+    }
+
+    #[test]
+    fn it_finds_every_maximal_clique() {
+        // Check against every subset of a pseudorandom graph
+        let names = (0..12).map(|i| format!("n{i:02}")).collect::<Vec<_>>();
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut network = network::Network::new();
+        for (i, a) in names.iter().enumerate() {
+            for b in names[i + 1..].iter() {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                if !state.is_multiple_of(3) {
+                    network.insert((a.as_str(), b.as_str()));
+                    network.insert((b.as_str(), a.as_str()));
+                }
+            }
+        }
+        let graph = Graph::new(&network);
+        let nodes = graph.nodes().collect::<Vec<_>>();
+
+        let is_clique = |set: &[&str]| {
+            set.iter()
+                .enumerate()
+                .all(|(i, a)| set[i + 1..].iter().all(|b| graph.connected(a, b)))
+        };
+        let mut expected = (1..1u32 << nodes.len())
+            .map(|mask| {
+                (0..nodes.len())
+                    .filter(|i| mask & 1 << i != 0)
+                    .map(|i| nodes[i])
+                    .collect::<Vec<_>>()
+            })
+            .filter(|set| is_clique(set))
+            .filter(|set| {
+                nodes
+                    .iter()
+                    .all(|node| set.contains(node) || !set.iter().all(|n| graph.connected(n, node)))
+            })
+            .collect::<Vec<_>>();
+        expected.sort();
+
+        assert!(expected.len() > 5);
+        assert_eq!(graph.maximal_cliques(), expected);
+    }
+
+    #[test]
+    fn it_finds_cliques_of_any_size() {
+        let network = network::parse(EXAMPLE).unwrap();
+        let graph = Graph::new(&network);
+
+        let with_t = graph.cliques_containing(3, |node| node.starts_with('t'));
+        assert_eq!(with_t.len(), 7);
+        assert_eq!(with_t[0], vec!["co", "de", "ta"]);
+        assert_eq!(graph.cliques_containing(3, |_| true).len(), 12);
+        assert_eq!(
+            graph.cliques_containing(4, |_| true),
+            vec![vec!["co", "de", "ka", "ta"]]
+        );
+        assert!(graph.cliques_containing(5, |_| true).is_empty());
+    }
+}
@@ -0,0 +1,26 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod analysis;
+pub mod graph;
+pub mod network;
+pub mod part1;
+pub mod part2;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input<'a> = network::Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        network::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
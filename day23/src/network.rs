@@ -0,0 +1,66 @@
+use common::{parse, ParseError};
+use std::collections::HashSet;
+
+/// Every link between two computers, stored in both directions.
+pub type Network<'a> = HashSet<(&'a str, &'a str)>;
+
+pub fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let mut connections: Network = HashSet::new();
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (a, b) = parse::split_once(input, line, "-")?;
+        if a.is_empty() || b.is_empty() {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected two computers in `{line}`"),
+            ));
+        }
+        if a == b {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("`{a}` can't link to itself"),
+            ));
+        }
+        connections.insert((a, b));
+        connections.insert((b, a));
+    }
+
+    Ok(connections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_links_both_ways() {
+        let connections = parse("kh-tc\nqp-kh\n").unwrap();
+
+        assert_eq!(connections.len(), 4);
+        assert!(connections.contains(&("kh", "tc")));
+        assert!(connections.contains(&("tc", "kh")));
+        assert!(connections.contains(&("kh", "qp")));
+    }
+
+    #[test]
+    fn it_rejects_lines_without_a_link() {
+        assert_eq!(
+            parse("kh-tc\nqpkh").unwrap_err().to_string(),
+            "2:1: expected `-` in `qpkh`"
+        );
+        assert_eq!(
+            parse("kh-tc\n-kh").unwrap_err().to_string(),
+            "2:1: expected two computers in `-kh`"
+        );
+        assert_eq!(
+            parse("kh-").unwrap_err().to_string(),
+            "1:1: expected two computers in `kh-`"
+        );
+        assert_eq!(
+            parse("kh-tc\n  ab-ab").unwrap_err().to_string(),
+            "2:3: `ab` can't link to itself"
+        );
+    }
+}
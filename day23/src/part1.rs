@@ -0,0 +1,60 @@
+use crate::{graph::Graph, network::Network};
+use common::Answer;
+
+/// The number of sets of three connected computers with at least one whose
+/// name starts with `t`.
+fn count_parties(connections: &Network) -> usize {
+    Graph::new(connections)
+        .cliques_containing(3, |computer| computer.starts_with('t'))
+        .len()
+}
+
+pub fn solve(connections: &Network) -> Answer {
+    count_parties(connections).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network;
+
+    #[test]
+    fn it_solves() {
+        let input = "kh-tc
+        qp-kh
+        de-cg
+        ka-co
+        yn-aq
+        qp-ub
+        cg-tb
+        vc-aq
+        tb-ka
+        wh-tc
+        yn-cg
+        kh-ub
+        ta-co
+        de-co
+        tc-td
+        tb-wq
+        wh-td
+        ta-ka
+        td-qp
+        aq-cg
+        wq-ub
+        ub-vc
+        de-ta
+        wq-aq
+        wq-vc
+        wh-yn
+        ka-de
+        kh-ta
+        co-tc
+        wh-qp
+        tb-vc
+        td-yn"
+            .to_string();
+        let conns = network::parse(&input).unwrap();
+
+        assert_eq!(count_parties(&conns), 7);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{graph::Graph, network::Network};
+use common::Answer;
+
+/// The largest set of computers that are all connected to each other, as
+/// their sorted names.
+fn find_password(connections: &Network) -> String {
+    Graph::new(connections).maximum_clique().join(",")
+}
+
+pub fn solve(connections: &Network) -> Answer {
+    find_password(connections).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network;
+
+    #[test]
+    fn it_solves() {
+        let input = "kh-tc
+        qp-kh
+        de-cg
+        ka-co
+        yn-aq
+        qp-ub
+        cg-tb
+        vc-aq
+        tb-ka
+        wh-tc
+        yn-cg
+        kh-ub
+        ta-co
+        de-co
+        tc-td
+        tb-wq
+        wh-td
+        ta-ka
+        td-qp
+        aq-cg
+        wq-ub
+        ub-vc
+        de-ta
+        wq-aq
+        wq-vc
+        wh-yn
+        ka-de
+        kh-ta
+        co-tc
+        wh-qp
+        tb-vc
+        td-yn"
+            .to_string();
+        let conns = network::parse(&input).unwrap();
+
+        assert_eq!(find_password(&conns), "co,de,ka,ta".to_string());
+    }
+}
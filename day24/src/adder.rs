@@ -0,0 +1,455 @@
+use crate::{
+    device::{Device, Gate, Logic},
+    simulator::{SimError, Simulator},
+};
+use std::{collections::HashMap, fmt};
+
+/// The gates that make up one bit of a ripple-carry adder, by output wire.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Bit {
+    pub index: usize,
+    /// `x XOR y`, which is the sum for bit 0.
+    pub half_sum: String,
+    /// `x AND y`, which is the carry for bit 0.
+    pub half_carry: String,
+    /// `half_sum XOR carry in`, the `z` wire.
+    pub sum: String,
+    /// `half_sum AND carry in`. Bit 0 has no carry in.
+    pub carry_and: Option<String>,
+    /// `half_carry OR carry_and`, the carry into the next bit.
+    pub carry: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AdderError {
+    /// A gate an adder needs is missing, and swapping outputs won't fix it.
+    Unrecognised { bit: usize, reason: String },
+    /// Repairs kept finding more wires to swap.
+    TooManySwaps(Vec<(String, String)>),
+    /// The repaired gates can't be simulated.
+    Broken(SimError),
+    /// The repaired circuit matches an adder but adds these wrongly.
+    WrongSum { x: u64, y: u64, z: u64 },
+    /// The inputs have this many bits, so their sum doesn't fit in a `u64`.
+    TooWide(usize),
+}
+
+impl fmt::Display for AdderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdderError::Unrecognised { bit, reason } => {
+                write!(f, "bit {bit} isn't part of a ripple-carry adder: {reason}")
+            }
+            AdderError::TooManySwaps(swaps) => {
+                write!(f, "gave up after {} swaps", swaps.len())
+            }
+            AdderError::Broken(err) => write!(f, "the repaired gates don't work: {err}"),
+            AdderError::WrongSum { x, y, z } => write!(f, "{x} + {y} gave {z}"),
+            AdderError::TooWide(bits) => {
+                write!(f, "{bits}-bit inputs are too wide to check, the most is 63")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdderError {}
+
+/// What stops the gates matching an adder at some bit.
+#[derive(Debug, PartialEq)]
+enum Fault {
+    /// Swapping the outputs of the gates driving these wires gets further.
+    Swap(String, String),
+    Unrecognised {
+        bit: usize,
+        reason: String,
+    },
+}
+
+/// A wire of a bus, e.g. `x07`.
+pub(crate) fn bus(name: char, bit: usize) -> String {
+    format!("{name}{bit:02}")
+}
+
+/// The gates indexed by what they read.
+struct Netlist<'a> {
+    gates: &'a [Gate],
+    by_inputs: HashMap<(Logic, &'a str, &'a str), &'a str>,
+}
+
+impl<'a> Netlist<'a> {
+    fn new(gates: &'a [Gate]) -> Self {
+        let by_inputs = gates
+            .iter()
+            .map(|gate| {
+                let (a, b) = ordered(&gate.in0, &gate.in1);
+                ((gate.logic, a, b), gate.out.as_str())
+            })
+            .collect();
+
+        Netlist { gates, by_inputs }
+    }
+
+    /// The output of the gate that combines `a` and `b` with `logic`.
+    fn find(&self, logic: Logic, a: &str, b: &str) -> Option<&'a str> {
+        let (a, b) = ordered(a, b);
+        self.by_inputs.get(&(logic, a, b)).copied()
+    }
+
+    /// When no `logic` gate reads both `a` and `b`, one of them is on the
+    /// wrong wire. A gate that reads one of them shows what the other should
+    /// have been.
+    fn fix(&self, bit: usize, logic: Logic, a: &str, b: &str) -> Fault {
+        for gate in self.gates.iter().filter(|gate| gate.logic == logic) {
+            for (read, other) in [(&gate.in0, &gate.in1), (&gate.in1, &gate.in0)] {
+                if read == a {
+                    return Fault::Swap(b.to_string(), other.clone());
+                }
+                if read == b {
+                    return Fault::Swap(a.to_string(), other.clone());
+                }
+            }
+        }
+
+        Fault::Unrecognised {
+            bit,
+            reason: format!("no {logic:?} gate reads `{a}` or `{b}`"),
+        }
+    }
+}
+
+fn ordered<'a>(a: &'a str, b: &'a str) -> (&'a str, &'a str) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn missing(bit: usize, logic: Logic) -> Fault {
+    Fault::Unrecognised {
+        bit,
+        reason: format!("no {logic:?} gate reads its `x` and `y`"),
+    }
+}
+
+/// Matches the gates against a ripple-carry adder of `bits` input bits, from
+/// the lowest bit up, stopping at the first gate that doesn't fit.
+fn analyze(gates: &[Gate], bits: usize) -> Result<Vec<Bit>, Fault> {
+    let netlist = Netlist::new(gates);
+    let mut found: Vec<Bit> = Vec::new();
+
+    for index in 0..bits {
+        let (x, y, z) = (bus('x', index), bus('y', index), bus('z', index));
+        let half_sum = netlist
+            .find(Logic::Xor, &x, &y)
+            .ok_or_else(|| missing(index, Logic::Xor))?;
+        let half_carry = netlist
+            .find(Logic::And, &x, &y)
+            .ok_or_else(|| missing(index, Logic::And))?;
+
+        let Some(carry_in) = found.last().map(|bit| bit.carry.as_str()) else {
+            // Bit 0 is a half adder
+            if half_sum != z {
+                return Err(Fault::Swap(half_sum.to_string(), z));
+            }
+            found.push(Bit {
+                index,
+                half_sum: half_sum.to_string(),
+                half_carry: half_carry.to_string(),
+                sum: z,
+                carry_and: None,
+                carry: half_carry.to_string(),
+            });
+            continue;
+        };
+
+        let sum = netlist
+            .find(Logic::Xor, half_sum, carry_in)
+            .ok_or_else(|| netlist.fix(index, Logic::Xor, half_sum, carry_in))?;
+        if sum != z {
+            return Err(Fault::Swap(sum.to_string(), z));
+        }
+
+        let carry_and = netlist
+            .find(Logic::And, half_sum, carry_in)
+            .ok_or_else(|| netlist.fix(index, Logic::And, half_sum, carry_in))?;
+        let carry = netlist
+            .find(Logic::Or, half_carry, carry_and)
+            .ok_or_else(|| netlist.fix(index, Logic::Or, half_carry, carry_and))?;
+
+        found.push(Bit {
+            index,
+            half_sum: half_sum.to_string(),
+            half_carry: half_carry.to_string(),
+            sum: z,
+            carry_and: Some(carry_and.to_string()),
+            carry: carry.to_string(),
+        });
+    }
+
+    // The last carry out is the top bit of the sum
+    let top = bus('z', bits);
+    match found.last() {
+        Some(bit) if bit.carry != top => Err(Fault::Swap(bit.carry.clone(), top)),
+        _ => Ok(found),
+    }
+}
+
+/// Swaps the outputs of the gates driving wires `a` and `b`.
+pub(crate) fn swap(gates: &mut [Gate], a: &str, b: &str) {
+    for gate in gates.iter_mut() {
+        if gate.out == a {
+            gate.out = b.to_string();
+        } else if gate.out == b {
+            gate.out = a.to_string();
+        }
+    }
+}
+
+/// The number of bits in each of the device's inputs.
+fn input_bits(device: &Device) -> usize {
+    device
+        .wires
+        .keys()
+        .filter(|wire| wire.starts_with('x'))
+        .count()
+}
+
+/// Sums to check an adder with: every edge case of a single bit, all bits
+/// set, alternating bits, and a fixed sequence of pseudorandom values, for
+/// inputs of up to 63 bits.
+fn test_cases(bits: usize) -> Vec<(u64, u64)> {
+    let max = (1 << bits) - 1;
+    let mut cases = vec![
+        (0, 0),
+        (max, 0),
+        (0, max),
+        (max, max),
+        (max, 1),
+        (0x5555_5555_5555_5555 & max, 0xaaaa_aaaa_aaaa_aaaa & max),
+    ];
+    for bit in 0..bits {
+        cases.extend([(1 << bit, 0), (0, 1 << bit), (1 << bit, 1 << bit)]);
+    }
+
+    // xorshift64
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state & max
+    };
+    cases.extend((0..100).map(|_| (next(), next())));
+
+    cases
+}
+
+/// Checks the gates add every test case correctly. The sum has a bit more
+/// than the inputs, so they can have at most 63.
+pub fn verify(gates: &[Gate], bits: usize) -> Result<(), AdderError> {
+    if bits >= 64 {
+        return Err(AdderError::TooWide(bits));
+    }
+    let inputs = (0..bits)
+        .flat_map(|bit| [(bus('x', bit), 0), (bus('y', bit), 0)])
+        .collect();
+    let simulator = Simulator::new(&inputs, gates).map_err(AdderError::Broken)?;
+
+    for (x, y) in test_cases(bits) {
+        let z = simulator.eval(x, y);
+        if z != x + y {
+            return Err(AdderError::WrongSum { x, y, z });
+        }
+    }
+
+    Ok(())
+}
+
+/// A device turned back into a working adder.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Repair {
+    /// The pairs of wires whose gates had their outputs swapped.
+    pub swaps: Vec<(String, String)>,
+    /// The gates of each bit once repaired.
+    pub bits: Vec<Bit>,
+    pub gates: Vec<Gate>,
+}
+
+impl Repair {
+    /// Every swapped wire, sorted.
+    pub fn wires(&self) -> Vec<&str> {
+        let mut wires = self
+            .swaps
+            .iter()
+            .flat_map(|(a, b)| [a.as_str(), b.as_str()])
+            .collect::<Vec<_>>();
+        wires.sort();
+        wires
+    }
+
+    /// The device with its initial values and the repaired gates.
+    pub fn apply(&self, device: &Device) -> Device {
+        Device {
+            wires: device.wires.clone(),
+            gates: self.gates.clone(),
+        }
+    }
+}
+
+/// Swaps gate outputs until the device matches a ripple-carry adder, then
+/// checks it adds correctly.
+pub fn repair(device: &Device) -> Result<Repair, AdderError> {
+    let bits = input_bits(device);
+    let mut gates = device.gates.clone();
+    let mut swaps = Vec::new();
+
+    let found = loop {
+        match analyze(&gates, bits) {
+            Ok(found) => break found,
+            Err(Fault::Unrecognised { bit, reason }) => {
+                return Err(AdderError::Unrecognised { bit, reason })
+            }
+            Err(Fault::Swap(a, b)) => {
+                if swaps.len() == bits {
+                    return Err(AdderError::TooManySwaps(swaps));
+                }
+                swap(&mut gates, &a, &b);
+                swaps.push((a, b));
+            }
+        }
+    };
+
+    verify(&gates, bits)?;
+
+    Ok(Repair {
+        swaps,
+        bits: found,
+        gates,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device;
+
+    /// A working adder of `bits` bits with made-up names for the wires
+    /// inside it.
+    pub(crate) fn adder(bits: usize) -> String {
+        let mut lines = Vec::new();
+        for bit in 0..bits {
+            lines.push(format!("x{bit:02}: 0"));
+            lines.push(format!("y{bit:02}: 0"));
+        }
+        lines.push(String::new());
+
+        lines.push("x00 XOR y00 -> z00".to_string());
+        lines.push("y00 AND x00 -> c00".to_string());
+        for bit in 1..bits {
+            let carry_in = format!("c{:02}", bit - 1);
+            let carry = if bit == bits - 1 {
+                bus('z', bits)
+            } else {
+                format!("c{bit:02}")
+            };
+            lines.push(format!("x{bit:02} XOR y{bit:02} -> h{bit:02}"));
+            lines.push(format!("x{bit:02} AND y{bit:02} -> a{bit:02}"));
+            lines.push(format!("{carry_in} XOR h{bit:02} -> z{bit:02}"));
+            lines.push(format!("h{bit:02} AND {carry_in} -> t{bit:02}"));
+            lines.push(format!("a{bit:02} OR t{bit:02} -> {carry}"));
+        }
+
+        lines.join("\n")
+    }
+
+    fn swapped(bits: usize, pairs: &[(&str, &str)]) -> Device {
+        let mut device = device::parse(&adder(bits)).unwrap();
+        for (a, b) in pairs {
+            swap(&mut device.gates, a, b);
+        }
+        device
+    }
+
+    #[test]
+    fn it_recognises_a_working_adder() {
+        let device = device::parse(&adder(4)).unwrap();
+        let repair = repair(&device).unwrap();
+
+        assert!(repair.swaps.is_empty());
+        assert_eq!(repair.bits.len(), 4);
+        assert_eq!(
+            repair.bits[2],
+            Bit {
+                index: 2,
+                half_sum: "h02".to_string(),
+                half_carry: "a02".to_string(),
+                sum: "z02".to_string(),
+                carry_and: Some("t02".to_string()),
+                carry: "c02".to_string(),
+            }
+        );
+        assert_eq!(repair.bits[3].carry, "z04");
+        assert_eq!(Simulator::from_device(&device).unwrap().eval(9, 11), 20);
+    }
+
+    #[test]
+    fn it_repairs_swapped_outputs() {
+        let device = swapped(
+            8,
+            &[
+                ("z01", "a01"),
+                ("h03", "a03"),
+                ("z05", "c05"),
+                ("t07", "z07"),
+            ],
+        );
+        assert!(verify(&device.gates, 8).is_err());
+
+        let repair = repair(&device).unwrap();
+        assert_eq!(
+            repair.wires(),
+            vec!["a01", "a03", "c05", "h03", "t07", "z01", "z05", "z07"]
+        );
+        assert_eq!(verify(&repair.gates, 8), Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_circuits_that_are_not_adders() {
+        let device = device::parse(&adder(3).replace("a01 OR t01", "a01 AND t01")).unwrap();
+
+        assert_eq!(
+            repair(&device),
+            Err(AdderError::Unrecognised {
+                bit: 1,
+                reason: "no Or gate reads `a01` or `t01`".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_finds_wrong_sums() {
+        let device = device::parse(&adder(3)).unwrap();
+        let mut gates = device.gates.clone();
+        swap(&mut gates, "z00", "z01");
+
+        assert_eq!(
+            verify(&gates, 3),
+            Err(AdderError::WrongSum { x: 7, y: 7, z: 13 })
+        );
+    }
+
+    #[test]
+    fn it_checks_adders_up_to_63_bits() {
+        let device = device::parse(&adder(63)).unwrap();
+
+        assert_eq!(verify(&device.gates, 63), Ok(()));
+        assert_eq!(verify(&device.gates, 64), Err(AdderError::TooWide(64)));
+        assert_eq!(
+            repair(&device::parse(&adder(64)).unwrap())
+                .unwrap_err()
+                .to_string(),
+            "64-bit inputs are too wide to check, the most is 63"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+// Prints the gates as a Graphviz graph, or a Mermaid flowchart with
+// `--mermaid`, with the wires part 2 swaps drawn in red.
+// To run: `cargo run --bin dot -- src/bin/input.txt | dot -Tsvg > device.svg`
+
+use common::{args, Solution};
+use day24::{adder, dot, Day24};
+use std::{env, fs};
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let input = fs::read_to_string(&path).expect("to read file");
+    let device = Day24::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+
+    let repair = adder::repair(&device);
+    let flagged = match &repair {
+        Ok(repair) => repair.wires(),
+        Err(err) => {
+            eprintln!("not highlighting any wires: {err}");
+            Vec::new()
+        }
+    };
+
+    if args.iter().any(|arg| arg == "--mermaid") {
+        println!("{}", dot::to_mermaid(&device, &flagged));
+    } else {
+        println!("{}", dot::to_dot(&device, &flagged));
+    }
+}
@@ -0,0 +1,21 @@
+// Prints the gates as a structural Verilog module, after fixing the swapped
+// wires with `--repaired`.
+// To run: `cargo run --bin verilog -- src/bin/input.txt --repaired > adder.v`
+
+use common::{args, Solution};
+use day24::{adder, verilog, Day24};
+use std::{env, fs};
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args::path(&args, "src/bin/input.txt").to_string();
+    let input = fs::read_to_string(&path).expect("to read file");
+    let mut device = Day24::parse(&input).unwrap_or_else(|err| panic!("{path}:{err}"));
+
+    if args.iter().any(|arg| arg == "--repaired") {
+        let repair = adder::repair(&device).unwrap_or_else(|err| panic!("{path}: {err}"));
+        device = repair.apply(&device);
+    }
+
+    println!("{}", verilog::to_verilog(&device, "device"));
+}
@@ -0,0 +1,251 @@
+use common::{parse, ParseError};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Logic {
+    And,
+    Or,
+    Xor,
+    Not,
+    Nand,
+    Nor,
+    Xnor,
+}
+
+impl Logic {
+    pub fn apply(self, in0: u8, in1: u8) -> u8 {
+        match self {
+            Logic::And => in0 & in1,
+            Logic::Or => in0 | in1,
+            Logic::Xor => in0 ^ in1,
+            Logic::Not => 1 - in0,
+            Logic::Nand => 1 - (in0 & in1),
+            Logic::Nor => 1 - (in0 | in1),
+            Logic::Xnor => 1 - (in0 ^ in1),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Logic::And => "AND",
+            Logic::Or => "OR",
+            Logic::Xor => "XOR",
+            Logic::Not => "NOT",
+            Logic::Nand => "NAND",
+            Logic::Nor => "NOR",
+            Logic::Xnor => "XNOR",
+        }
+    }
+}
+
+/// A gate driving `out`. `NOT` only reads `in0`, and `in1` is the same wire.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gate {
+    pub(crate) in0: String,
+    pub(crate) in1: String,
+    pub(crate) logic: Logic,
+    pub(crate) out: String,
+}
+
+impl Gate {
+    /// The wires the gate reads.
+    pub fn inputs(&self) -> Vec<&str> {
+        match self.logic {
+            Logic::Not => vec![&self.in0],
+            _ => vec![&self.in0, &self.in1],
+        }
+    }
+}
+
+/// The bit of the `x`, `y` or `z` bus a wire carries, e.g. 7 for `z07`.
+pub(crate) fn bus_bit(wire: &str, bus: char) -> Option<usize> {
+    wire.strip_prefix(bus)
+        .filter(|bit| !bit.is_empty() && bit.chars().all(|c| c.is_ascii_digit()))?
+        .parse()
+        .ok()
+}
+
+/// The initial values of the input wires and the gates connecting them.
+#[derive(Debug, PartialEq)]
+pub struct Device {
+    pub(crate) wires: HashMap<String, u8>,
+    pub(crate) gates: Vec<Gate>,
+}
+
+pub fn parse(input: &str) -> Result<Device, ParseError> {
+    let mut wires = HashMap::new();
+    let mut gates = Vec::new();
+
+    let (initial, connections) = input
+        .trim()
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::eof(input, "a blank line before the gates"))?;
+
+    for wire in initial.lines() {
+        let (net, value) = parse::split_once(input, wire.trim(), ":")?;
+        let value = match value.trim() {
+            "0" => 0,
+            "1" => 1,
+            value => {
+                return Err(ParseError::at(
+                    input,
+                    value,
+                    format!("expected `0` or `1`, found `{value}`"),
+                ))
+            }
+        };
+
+        wires.entry(net.trim().to_string()).or_insert(value);
+    }
+
+    for line in connections.lines() {
+        let line = line.trim();
+        let (lhs, out) = parse::split_once(input, line, "->")?;
+
+        let (in0, logic, in1) = match lhs.split_whitespace().collect::<Vec<_>>()[..] {
+            ["NOT", in0] => (in0, "NOT", in0),
+            [in0, logic, in1] => (in0, logic, in1),
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected `<wire> <op> <wire>`, found `{}`", lhs.trim()),
+                ))
+            }
+        };
+
+        let logic = match logic {
+            "AND" => Logic::And,
+            "OR" => Logic::Or,
+            "XOR" => Logic::Xor,
+            "NOT" => Logic::Not,
+            "NAND" => Logic::Nand,
+            "NOR" => Logic::Nor,
+            "XNOR" => Logic::Xnor,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    logic,
+                    format!("unknown gate op `{logic}`"),
+                ))
+            }
+        };
+
+        gates.push(Gate {
+            in0: in0.to_string(),
+            in1: in1.to_string(),
+            logic,
+            out: out.trim().to_string(),
+        });
+    }
+
+    Ok(Device { wires, gates })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses() {
+        let input = "x00: 1
+        x01: 1
+        x02: 1
+        y00: 0
+        y01: 1
+        y02: 0
+
+        x00 AND y00 -> z00
+        x01 XOR y01 -> z01
+        x02 OR y02 -> z02"
+            .to_string();
+        let device = parse(&input).unwrap();
+
+        assert_eq!(
+            device.wires,
+            HashMap::from([
+                ("x00".to_string(), 1),
+                ("x01".to_string(), 1),
+                ("x02".to_string(), 1),
+                ("y00".to_string(), 0),
+                ("y01".to_string(), 1),
+                ("y02".to_string(), 0),
+            ])
+        );
+        assert_eq!(device.gates.len(), 3);
+        assert_eq!(
+            device.gates,
+            vec![
+                Gate {
+                    in0: "x00".to_string(),
+                    in1: "y00".to_string(),
+                    logic: Logic::And,
+                    out: "z00".to_string()
+                },
+                Gate {
+                    in0: "x01".to_string(),
+                    in1: "y01".to_string(),
+                    logic: Logic::Xor,
+                    out: "z01".to_string()
+                },
+                Gate {
+                    in0: "x02".to_string(),
+                    in1: "y02".to_string(),
+                    logic: Logic::Or,
+                    out: "z02".to_string()
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn it_parses_other_gates() {
+        let device =
+            parse("x00: 1\ny00: 0\n\nNOT x00 -> a\nx00 NAND a -> b\na XNOR b -> z00").unwrap();
+
+        assert_eq!(
+            device.gates[0],
+            Gate {
+                in0: "x00".to_string(),
+                in1: "x00".to_string(),
+                logic: Logic::Not,
+                out: "a".to_string()
+            }
+        );
+        assert_eq!(device.gates[0].inputs(), vec!["x00"]);
+        assert_eq!(device.gates[1].logic, Logic::Nand);
+        assert_eq!(device.gates[2].inputs(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn it_applies_logic() {
+        let table =
+            |logic: Logic| [(0, 0), (0, 1), (1, 0), (1, 1)].map(|(in0, in1)| logic.apply(in0, in1));
+
+        assert_eq!(table(Logic::And), [0, 0, 0, 1]);
+        assert_eq!(table(Logic::Or), [0, 1, 1, 1]);
+        assert_eq!(table(Logic::Xor), [0, 1, 1, 0]);
+        assert_eq!(table(Logic::Not), [1, 1, 0, 0]);
+        assert_eq!(table(Logic::Nand), [1, 1, 1, 0]);
+        assert_eq!(table(Logic::Nor), [1, 0, 0, 0]);
+        assert_eq!(table(Logic::Xnor), [1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn it_rejects_bad_gates() {
+        let input = "x00: 1\ny00: 0\n\nx00 MUX y00 -> z00";
+        assert_eq!(
+            parse(input).unwrap_err().to_string(),
+            "4:5: unknown gate op `MUX`"
+        );
+
+        let input = "x00: 2\n\nx00 AND y00 -> z00";
+        assert_eq!(
+            parse(input).unwrap_err().to_string(),
+            "1:6: expected `0` or `1`, found `2`"
+        );
+
+        assert!(parse("x00: 1").is_err());
+        assert!(parse("x00: 1\n\nx00 AND -> z00").is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::device::{bus_bit, Device, Logic};
+use std::collections::BTreeSet;
+
+const BUSES: [char; 3] = ['x', 'y', 'z'];
+const FLAGGED: &str = "#e63946";
+
+fn colour(logic: Logic) -> &'static str {
+    match logic {
+        Logic::And => "#8ecae6",
+        Logic::Or => "#ffb703",
+        Logic::Xor => "#90be6d",
+        Logic::Not => "#d9d9d9",
+        Logic::Nand => "#c3e4f2",
+        Logic::Nor => "#ffdb81",
+        Logic::Xnor => "#c8deb6",
+    }
+}
+
+const LOGIC: [Logic; 7] = [
+    Logic::And,
+    Logic::Or,
+    Logic::Xor,
+    Logic::Not,
+    Logic::Nand,
+    Logic::Nor,
+    Logic::Xnor,
+];
+
+fn on_bus(wire: &str) -> bool {
+    BUSES.iter().any(|&bus| bus_bit(wire, bus).is_some())
+}
+
+/// Every wire the device uses, sorted.
+fn wires(device: &Device) -> BTreeSet<&str> {
+    device
+        .wires
+        .keys()
+        .map(|wire| wire.as_str())
+        .chain(
+            device
+                .gates
+                .iter()
+                .flat_map(|gate| [&gate.in0, &gate.in1, &gate.out])
+                .map(|wire| wire.as_str()),
+        )
+        .collect()
+}
+
+/// A wire's name as a quoted DOT ID.
+fn quote(wire: &str) -> String {
+    format!("\"{}\"", wire.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The DOT ID of a gate. Wire names can't contain spaces, so this can't be
+/// the name of a wire.
+fn gate_id(i: usize) -> String {
+    format!("\"gate {i}\"")
+}
+
+/// A wire as a Mermaid node ID, which can only have letters, digits and
+/// `_`. Anything else is written as its code point between `_`s, so two
+/// wires never share an ID.
+fn mermaid_id(wire: &str) -> String {
+    let mut id = "w_".to_string();
+    for c in wire.chars() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c);
+        } else {
+            id.push_str(&format!("_{:x}_", c as u32));
+        }
+    }
+    id
+}
+
+/// A wire as a Mermaid node, with its name as the label.
+fn mermaid_node(wire: &str, shape: [&str; 2]) -> String {
+    let [open, close] = shape;
+    format!(
+        "{}{open}\"{}\"{close}",
+        mermaid_id(wire),
+        wire.replace('"', "#quot;")
+    )
+}
+
+/// The device as a Graphviz graph, with a node for each wire and gate. Gates
+/// are coloured by their logic, the bits of each bus are grouped together
+/// and the `flagged` wires are drawn in red.
+pub fn to_dot(device: &Device, flagged: &[&str]) -> String {
+    let wires = wires(device);
+    let mut lines = vec![
+        "digraph device {".to_string(),
+        "    rankdir=LR;".to_string(),
+        "    node [fontname=\"monospace\"];".to_string(),
+    ];
+
+    let wire = |wire: &str| {
+        let shape = if on_bus(wire) { "circle" } else { "plaintext" };
+        let id = quote(wire);
+        if flagged.contains(&wire) {
+            format!(
+                "{id} [shape={shape}, color=\"{FLAGGED}\", fontcolor=\"{FLAGGED}\", penwidth=3];"
+            )
+        } else {
+            format!("{id} [shape={shape}];")
+        }
+    };
+
+    for bus in BUSES {
+        lines.push(format!("    subgraph cluster_{bus} {{"));
+        lines.push(format!("        label=\"{bus}\";"));
+        lines.push("        rank=same;".to_string());
+        for &bit in wires.iter().filter(|w| bus_bit(w, bus).is_some()) {
+            lines.push(format!("        {}", wire(bit)));
+        }
+        lines.push("    }".to_string());
+    }
+    for &other in wires.iter().filter(|w| !on_bus(w)) {
+        lines.push(format!("    {}", wire(other)));
+    }
+
+    for (i, gate) in device.gates.iter().enumerate() {
+        lines.push(format!(
+            "    {} [label=\"{}\", shape=box, style=filled, fillcolor=\"{}\"];",
+            gate_id(i),
+            gate.logic.name(),
+            colour(gate.logic)
+        ));
+    }
+
+    let edge = |from: String, to: String, wire: &str| {
+        if flagged.contains(&wire) {
+            format!("    {from} -> {to} [color=\"{FLAGGED}\", penwidth=3];")
+        } else {
+            format!("    {from} -> {to};")
+        }
+    };
+    for (i, gate) in device.gates.iter().enumerate() {
+        for input in gate.inputs() {
+            lines.push(edge(quote(input), gate_id(i), input));
+        }
+        lines.push(edge(gate_id(i), quote(&gate.out), &gate.out));
+    }
+
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// The same graph as `to_dot` as a Mermaid flowchart, with a class for each
+/// kind of gate used. Wires get IDs starting `w_` and gates `gate_`, so a
+/// wire named like a keyword, such as `end`, can't break the chart.
+pub fn to_mermaid(device: &Device, flagged: &[&str]) -> String {
+    let wires = wires(device);
+    let mut lines = vec!["flowchart LR".to_string()];
+
+    for bus in BUSES {
+        lines.push(format!("    subgraph {bus}"));
+        for &bit in wires.iter().filter(|w| bus_bit(w, bus).is_some()) {
+            lines.push(format!("        {}", mermaid_node(bit, ["((", "))"])));
+        }
+        lines.push("    end".to_string());
+    }
+    for &other in wires.iter().filter(|w| !on_bus(w)) {
+        lines.push(format!("    {}", mermaid_node(other, ["[", "]"])));
+    }
+
+    for (i, gate) in device.gates.iter().enumerate() {
+        lines.push(format!("    gate_{i}[{}]", gate.logic.name()));
+        for input in gate.inputs() {
+            lines.push(format!("    {} --> gate_{i}", mermaid_id(input)));
+        }
+        lines.push(format!("    gate_{i} --> {}", mermaid_id(&gate.out)));
+    }
+
+    let used = LOGIC
+        .into_iter()
+        .filter(|&logic| device.gates.iter().any(|gate| gate.logic == logic));
+    for logic in used {
+        lines.push(format!(
+            "    classDef {} fill:{}",
+            logic.name().to_lowercase(),
+            colour(logic)
+        ));
+    }
+    lines.push(format!(
+        "    classDef flagged stroke:{FLAGGED},stroke-width:3px"
+    ));
+    for (i, gate) in device.gates.iter().enumerate() {
+        lines.push(format!(
+            "    class gate_{i} {}",
+            gate.logic.name().to_lowercase()
+        ));
+    }
+    for &wire in wires.iter().filter(|wire| flagged.contains(wire)) {
+        lines.push(format!("    class {} flagged", mermaid_id(wire)));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device;
+
+    const HALF_ADDER: &str = "x00: 1
+    y00: 0
+
+    x00 XOR y00 -> z00
+    x00 AND y00 -> z01";
+
+    #[test]
+    fn it_writes_dot() {
+        let device = device::parse(HALF_ADDER).unwrap();
+
+        assert_eq!(
+            to_dot(&device, &["z01"]),
+            r##"digraph device {
+    rankdir=LR;
+    node [fontname="monospace"];
+    subgraph cluster_x {
+        label="x";
+        rank=same;
+        "x00" [shape=circle];
+    }
+    subgraph cluster_y {
+        label="y";
+        rank=same;
+        "y00" [shape=circle];
+    }
+    subgraph cluster_z {
+        label="z";
+        rank=same;
+        "z00" [shape=circle];
+        "z01" [shape=circle, color="#e63946", fontcolor="#e63946", penwidth=3];
+    }
+    "gate 0" [label="XOR", shape=box, style=filled, fillcolor="#90be6d"];
+    "gate 1" [label="AND", shape=box, style=filled, fillcolor="#8ecae6"];
+    "x00" -> "gate 0";
+    "y00" -> "gate 0";
+    "gate 0" -> "z00";
+    "x00" -> "gate 1";
+    "y00" -> "gate 1";
+    "gate 1" -> "z01" [color="#e63946", penwidth=3];
+}"##
+        );
+    }
+
+    #[test]
+    fn it_keeps_internal_wires_out_of_the_buses() {
+        let device =
+            device::parse("x00: 1\ny00: 0\n\nx00 OR y00 -> abc\nabc OR x00 -> z00").unwrap();
+        let dot = to_dot(&device, &[]);
+
+        assert!(dot.contains("    }\n    \"abc\" [shape=plaintext];\n    \"gate 0\""));
+    }
+
+    #[test]
+    fn it_writes_mermaid() {
+        let device = device::parse(HALF_ADDER).unwrap();
+
+        assert_eq!(
+            to_mermaid(&device, &["z01"]),
+            "flowchart LR
+    subgraph x
+        w_x00((\"x00\"))
+    end
+    subgraph y
+        w_y00((\"y00\"))
+    end
+    subgraph z
+        w_z00((\"z00\"))
+        w_z01((\"z01\"))
+    end
+    gate_0[XOR]
+    w_x00 --> gate_0
+    w_y00 --> gate_0
+    gate_0 --> w_z00
+    gate_1[AND]
+    w_x00 --> gate_1
+    w_y00 --> gate_1
+    gate_1 --> w_z01
+    classDef and fill:#8ecae6
+    classDef xor fill:#90be6d
+    classDef flagged stroke:#e63946,stroke-width:3px
+    class gate_0 xor
+    class gate_1 and
+    class w_z01 flagged"
+        );
+    }
+
+    #[test]
+    fn it_keeps_wire_names_apart_from_gates_and_keywords() {
+        let device = device::parse(
+            "x00: 1\ny00: 0\n\nx00 AND y00 -> end\nend OR x00 -> g0\ng0 XOR y00 -> a-b\na-b OR x00 -> z00",
+        )
+        .unwrap();
+
+        let dot = to_dot(&device, &[]);
+        assert!(dot.contains("    \"g0\" [shape=plaintext];"));
+        assert!(dot.contains(
+            "    \"end\" -> \"gate 1\";\n    \"x00\" -> \"gate 1\";\n    \"gate 1\" -> \"g0\";"
+        ));
+
+        let mermaid = to_mermaid(&device, &["end"]);
+        assert!(mermaid.contains("\n    w_end[\"end\"]\n"));
+        assert!(mermaid.contains("\n    w_a_2d_b[\"a-b\"]\n"));
+        assert!(mermaid.contains("\n    gate_0 --> w_end\n"));
+        assert!(mermaid.contains("\n    w_end --> gate_1\n"));
+        assert!(mermaid.ends_with("\n    class w_end flagged"));
+        assert_eq!(mermaid.matches("\n    end").count(), 3);
+    }
+
+    #[test]
+    fn it_escapes_wire_names() {
+        assert_eq!(quote("a\"b"), "\"a\\\"b\"");
+        assert_eq!(mermaid_node("a\"b", ["[", "]"]), "w_a_22_b[\"a#quot;b\"]");
+    }
+}
@@ -0,0 +1,28 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod adder;
+pub mod device;
+pub mod dot;
+pub mod part1;
+pub mod part2;
+pub mod simulator;
+pub mod verilog;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input<'a> = device::Device;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        device::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+}
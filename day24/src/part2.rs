@@ -0,0 +1,42 @@
+use crate::{adder, device::Device};
+use common::Answer;
+
+/// The wires whose gates were swapped to make the device an adder, or why it
+/// couldn't be repaired.
+pub fn solve(device: &Device) -> Answer {
+    match adder::repair(device) {
+        Ok(repair) => repair.wires().join(",").into(),
+        Err(err) => Answer::Error(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device;
+
+    #[test]
+    fn it_finds_swapped_wires() {
+        let adder = "x00: 1
+        x01: 0
+        y00: 1
+        y01: 1
+
+        x00 XOR y00 -> z00
+        x00 AND y00 -> c00
+        x01 XOR y01 -> t01
+        t01 XOR c00 -> z01
+        x01 AND y01 -> a01
+        t01 AND c00 -> b01
+        a01 OR b01 -> z02";
+        let device = device::parse(adder).unwrap();
+        assert_eq!(solve(&device), "".into());
+
+        let swapped = adder
+            .replace("c00 -> z01", "c00 -> tmp")
+            .replace("y01 -> a01", "y01 -> z01")
+            .replace("c00 -> tmp", "c00 -> a01");
+        let device = device::parse(&swapped).unwrap();
+        assert_eq!(solve(&device), "a01,z01".into());
+    }
+}
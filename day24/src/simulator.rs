@@ -0,0 +1,248 @@
+use crate::device::{bus_bit, Device, Gate};
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SimError {
+    /// A gate reads a wire with no initial value and no gate driving it.
+    Undriven(String),
+    /// More than one gate drives the wire.
+    MultipleDrivers(String),
+    /// The wires on a combinational loop, in the order a signal goes round.
+    Loop(Vec<String>),
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimError::Undriven(wire) => write!(f, "wire `{wire}` is read but never driven"),
+            SimError::MultipleDrivers(wire) => {
+                write!(f, "wire `{wire}` is driven by more than one gate")
+            }
+            SimError::Loop(wires) => {
+                write!(f, "combinational loop through {}", wires.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
+
+/// Evaluates a netlist in one pass, with each gate after the gates that
+/// drive its inputs.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    initial: HashMap<String, u8>,
+    order: Vec<Gate>,
+}
+
+impl Simulator {
+    /// Orders the gates, checking every wire they read has exactly one
+    /// source and that no gate depends on its own output.
+    pub fn new(initial: &HashMap<String, u8>, gates: &[Gate]) -> Result<Self, SimError> {
+        let mut drivers = HashMap::new();
+        for (i, gate) in gates.iter().enumerate() {
+            if drivers.insert(gate.out.as_str(), i).is_some() {
+                return Err(SimError::MultipleDrivers(gate.out.clone()));
+            }
+        }
+
+        // Kahn's algorithm, counting each input driven by another gate
+        let mut waiting = vec![0; gates.len()];
+        let mut dependents = vec![Vec::new(); gates.len()];
+        for (i, gate) in gates.iter().enumerate() {
+            for input in gate.inputs() {
+                match drivers.get(input) {
+                    Some(&driver) => {
+                        waiting[i] += 1;
+                        dependents[driver].push(i);
+                    }
+                    None if initial.contains_key(input) => (),
+                    None => return Err(SimError::Undriven(input.to_string())),
+                }
+            }
+        }
+
+        let mut ready = (0..gates.len())
+            .filter(|&i| waiting[i] == 0)
+            .collect::<Vec<_>>();
+        let mut order = Vec::with_capacity(gates.len());
+        while let Some(i) = ready.pop() {
+            order.push(gates[i].clone());
+            for &dependent in dependents[i].iter() {
+                waiting[dependent] -= 1;
+                if waiting[dependent] == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+
+        if order.len() < gates.len() {
+            return Err(SimError::Loop(find_loop(gates, &drivers, &waiting)));
+        }
+
+        Ok(Simulator {
+            initial: initial.clone(),
+            order,
+        })
+    }
+
+    pub fn from_device(device: &Device) -> Result<Self, SimError> {
+        Simulator::new(&device.wires, &device.gates)
+    }
+
+    /// The value of every wire, starting from the initial values with
+    /// `inputs` set on top of them.
+    pub fn run(&self, inputs: &HashMap<String, u8>) -> HashMap<String, u8> {
+        let mut values = self.initial.clone();
+        values.extend(inputs.iter().map(|(wire, &value)| (wire.clone(), value)));
+
+        for gate in self.order.iter() {
+            let out = gate.logic.apply(values[&gate.in0], values[&gate.in1]);
+            values.insert(gate.out.clone(), out);
+        }
+
+        values
+    }
+
+    /// The number on the `z` wires, from the initial values.
+    pub fn output(&self) -> u64 {
+        read_bus(&self.run(&HashMap::new()), 'z')
+    }
+
+    /// The number on the `z` wires with the `x` and `y` inputs set to `x`
+    /// and `y`. Bits without an input wire are ignored.
+    pub fn eval(&self, x: u64, y: u64) -> u64 {
+        let inputs = self
+            .initial
+            .keys()
+            .filter_map(|wire| {
+                let value = match (bus_bit(wire, 'x'), bus_bit(wire, 'y')) {
+                    (Some(bit), _) => x.checked_shr(bit as u32)?,
+                    (_, Some(bit)) => y.checked_shr(bit as u32)?,
+                    _ => return None,
+                };
+                Some((wire.clone(), (value & 1) as u8))
+            })
+            .collect();
+
+        read_bus(&self.run(&inputs), 'z')
+    }
+}
+
+/// Reads the bits of a bus, e.g. `z00`, `z01`, ..., as a number.
+pub fn read_bus(values: &HashMap<String, u8>, bus: char) -> u64 {
+    values
+        .iter()
+        .filter_map(|(wire, &value)| Some((bus_bit(wire, bus)?, value)))
+        .filter(|&(bit, _)| bit < 64)
+        .fold(0, |n, (bit, value)| n | (value as u64) << bit)
+}
+
+/// Follows the inputs back from a gate Kahn's algorithm couldn't order
+/// until it reaches a gate it has already seen.
+fn find_loop(gates: &[Gate], drivers: &HashMap<&str, usize>, waiting: &[usize]) -> Vec<String> {
+    let unordered = |wire: &str| drivers.get(wire).copied().filter(|&i| waiting[i] > 0);
+    let mut path: Vec<usize> = Vec::new();
+    let mut gate = (0..gates.len()).find(|&i| waiting[i] > 0).unwrap();
+
+    while !path.contains(&gate) {
+        path.push(gate);
+        // An unordered gate is always waiting on another unordered gate
+        gate = gates[gate]
+            .inputs()
+            .into_iter()
+            .find_map(unordered)
+            .unwrap();
+    }
+
+    let start = path.iter().position(|&i| i == gate).unwrap();
+    let mut wires = path[start..]
+        .iter()
+        .rev()
+        .map(|&i| gates[i].out.clone())
+        .collect::<Vec<_>>();
+    let first = (0..wires.len()).min_by_key(|&i| &wires[i]).unwrap();
+    wires.rotate_left(first);
+    wires
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device;
+
+    fn simulator(input: &str) -> Result<Simulator, SimError> {
+        Simulator::from_device(&device::parse(input).unwrap())
+    }
+
+    #[test]
+    fn it_evaluates_in_one_pass() {
+        // The gates are listed in reverse, so each reads a wire set later
+        let simulator = simulator(
+            "x00: 1
+            x01: 0
+            y00: 1
+            y01: 0
+
+            c00 XOR b01 -> z01
+            x01 XOR y01 -> b01
+            x00 AND y00 -> c00
+            x00 XOR y00 -> z00",
+        )
+        .unwrap();
+
+        assert_eq!(simulator.output(), 0b10);
+        assert_eq!(simulator.eval(1, 2), 0b11);
+        assert_eq!(simulator.eval(3, 3), 0b10);
+    }
+
+    #[test]
+    fn it_supports_other_gates() {
+        let simulator = simulator(
+            "x00: 0
+            x01: 0
+            y00: 0
+            y01: 0
+
+            NOT x00 -> z00
+            x00 NAND y00 -> z01
+            x01 NOR y01 -> z02
+            x01 XNOR y01 -> z03",
+        )
+        .unwrap();
+
+        assert_eq!(simulator.eval(0, 0), 0b1111);
+        assert_eq!(simulator.eval(0b11, 0b10), 0b1010);
+        assert_eq!(simulator.eval(0b01, 0b01), 0b1100);
+    }
+
+    #[test]
+    fn it_reads_wide_buses() {
+        let mut input = (0..64)
+            .flat_map(|bit| [format!("x{bit:02}: 0"), format!("y{bit:02}: 0")])
+            .collect::<Vec<_>>();
+        input.push(String::new());
+        input.extend((0..64).map(|bit| format!("x{bit:02} XOR y{bit:02} -> z{bit:02}")));
+        let simulator = simulator(&input.join("\n")).unwrap();
+
+        assert_eq!(simulator.eval(u64::MAX, 1 << 63), u64::MAX >> 1);
+    }
+
+    #[test]
+    fn it_rejects_bad_netlists() {
+        let error = |input| simulator(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("x00: 1\n\nx00 AND y00 -> z00"),
+            "wire `y00` is read but never driven"
+        );
+        assert_eq!(
+            error("x00: 1\n\nx00 AND x00 -> z00\nNOT x00 -> z00"),
+            "wire `z00` is driven by more than one gate"
+        );
+        assert_eq!(
+            error("x00: 1\n\nb OR x00 -> z00\nx00 AND a -> b\nb XOR x00 -> c\nNOT c -> a"),
+            "combinational loop through a -> b -> c"
+        );
+    }
+}
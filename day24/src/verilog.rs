@@ -0,0 +1,560 @@
+use crate::device::{bus_bit, Device, Gate, Logic};
+use common::{parse, ParseError};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const KEYWORDS: [&str; 14] = [
+    "and",
+    "assign",
+    "endmodule",
+    "inout",
+    "input",
+    "module",
+    "nand",
+    "nor",
+    "not",
+    "or",
+    "output",
+    "wire",
+    "xnor",
+    "xor",
+];
+
+fn primitive(logic: Logic) -> &'static str {
+    match logic {
+        Logic::And => "and",
+        Logic::Or => "or",
+        Logic::Xor => "xor",
+        Logic::Not => "not",
+        Logic::Nand => "nand",
+        Logic::Nor => "nor",
+        Logic::Xnor => "xnor",
+    }
+}
+
+fn from_primitive(name: &str) -> Option<Logic> {
+    [
+        Logic::And,
+        Logic::Or,
+        Logic::Xor,
+        Logic::Not,
+        Logic::Nand,
+        Logic::Nor,
+        Logic::Xnor,
+    ]
+    .into_iter()
+    .find(|&logic| primitive(logic) == name)
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A wire as a Verilog identifier, escaped if it is a keyword or has
+/// characters an identifier can't.
+fn identifier(wire: &str) -> String {
+    if is_identifier(wire) && !KEYWORDS.contains(&wire) {
+        wire.to_string()
+    } else {
+        format!("\\{wire} ")
+    }
+}
+
+/// The device as a structural Verilog module. The initial `x` and `y` wires
+/// become input buses, `z` wires driven by gates an output bus, and each
+/// gate an instance of the matching primitive.
+pub fn to_verilog(device: &Device, module: &str) -> String {
+    let inputs = device
+        .wires
+        .keys()
+        .map(|wire| wire.as_str())
+        .collect::<BTreeSet<_>>();
+    let outputs = device
+        .gates
+        .iter()
+        .map(|gate| gate.out.as_str())
+        .collect::<BTreeSet<_>>();
+
+    // The width of each bus, and the wires that aren't on one
+    let mut buses = BTreeMap::new();
+    let mut others = BTreeSet::new();
+    for (wire, bus) in inputs
+        .iter()
+        .map(|wire| (wire, ['x', 'y']))
+        .chain(outputs.iter().map(|wire| (wire, ['z', 'z'])))
+    {
+        match bus
+            .into_iter()
+            .find_map(|bus| Some((bus, bus_bit(wire, bus)?)))
+        {
+            Some((bus, bit)) => {
+                let width = buses.entry(bus).or_insert(0);
+                *width = (bit + 1).max(*width);
+            }
+            None => {
+                others.insert(*wire);
+            }
+        }
+    }
+
+    let net = |wire: &str| {
+        let bus = if inputs.contains(wire) {
+            ['x', 'y']
+        } else {
+            ['z', 'z']
+        };
+        match bus
+            .into_iter()
+            .find_map(|bus| Some((bus, bus_bit(wire, bus)?)))
+        {
+            Some((bus, bit)) if buses.contains_key(&bus) => format!("{bus}[{bit}]"),
+            _ => identifier(wire),
+        }
+    };
+
+    let mut ports = buses
+        .iter()
+        .map(|(bus, width)| {
+            let direction = if *bus == 'z' { "output" } else { "input" };
+            format!("    {direction} wire [{}:0] {bus}", width - 1)
+        })
+        .collect::<Vec<_>>();
+    ports.extend(
+        others
+            .iter()
+            .filter(|wire| inputs.contains(*wire))
+            .map(|wire| format!("    input wire {}", identifier(wire))),
+    );
+
+    let mut lines = vec![format!("module {} (", identifier(module))];
+    lines.push(ports.join(",\n"));
+    lines.push(");".to_string());
+
+    for wire in others.iter().filter(|wire| !inputs.contains(*wire)) {
+        lines.push(format!("    wire {};", identifier(wire)));
+    }
+    lines.push(String::new());
+
+    for (i, gate) in device.gates.iter().enumerate() {
+        let terminals = std::iter::once(gate.out.as_str())
+            .chain(gate.inputs())
+            .map(net)
+            .collect::<Vec<_>>();
+        lines.push(format!(
+            "    {} g{i} ({});",
+            primitive(gate.logic),
+            terminals.join(", ")
+        ));
+    }
+
+    lines.push("endmodule".to_string());
+    lines.join("\n")
+}
+
+/// Splits Verilog source into identifiers, numbers and single punctuation
+/// characters, skipping whitespace and comments.
+fn tokenize(input: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut rest = input;
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return tokens;
+        }
+
+        let len = if rest.starts_with("//") {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]);
+            continue;
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+            continue;
+        } else if rest.starts_with('\\') {
+            // Escaped identifiers run to the next whitespace
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        } else {
+            let word = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(rest.len());
+            word.max(rest.chars().next().unwrap().len_utf8())
+        };
+
+        tokens.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<&'a str>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        let token = self
+            .peek()
+            .ok_or_else(|| ParseError::eof(self.input, expected))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn error(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::at(
+            self.input,
+            token,
+            format!("expected {expected}, found `{token}`"),
+        )
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        match self.next(&format!("`{expected}`"))? {
+            token if token == expected => Ok(()),
+            token => Err(self.error(token, &format!("`{expected}`"))),
+        }
+    }
+
+    /// Consumes the next token if it is `token`.
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, ParseError> {
+        let token = self.next("an identifier")?;
+        match token.strip_prefix('\\') {
+            Some(escaped) if !escaped.is_empty() => Ok(escaped),
+            None if is_identifier(token) && !KEYWORDS.contains(&token) => Ok(token),
+            _ => Err(self.error(token, "an identifier")),
+        }
+    }
+
+    fn number(&mut self) -> Result<usize, ParseError> {
+        let token = self.next("a number")?;
+        parse::number(self.input, token)
+    }
+
+    /// The bits of an optional `[msb:lsb]` range.
+    fn range(&mut self) -> Result<Option<Vec<usize>>, ParseError> {
+        if !self.eat("[") {
+            return Ok(None);
+        }
+        let msb = self.number()?;
+        self.expect(":")?;
+        let lsb = self.number()?;
+        self.expect("]")?;
+
+        Ok(Some((lsb.min(msb)..=lsb.max(msb)).collect()))
+    }
+
+    /// A wire, or one bit of a bus as `x[3]`, which is named `x03`.
+    fn net(&mut self) -> Result<String, ParseError> {
+        let name = self.identifier()?;
+        if !self.eat("[") {
+            return Ok(name.to_string());
+        }
+        let bit = self.number()?;
+        self.expect("]")?;
+
+        Ok(format!("{name}{bit:02}"))
+    }
+
+    /// The wires named by a declaration after `input`, `output` or `wire`,
+    /// one for each bit of a ranged one.
+    fn declaration(&mut self) -> Result<Vec<String>, ParseError> {
+        self.eat("wire");
+        let range = self.range()?;
+        let mut wires = Vec::new();
+        loop {
+            wires.extend(bits(self.identifier()?, &range));
+            // In a port list the next port may start with its direction
+            if self
+                .tokens
+                .get(self.pos + 1)
+                .is_some_and(|&next| next == "input" || next == "output")
+                || !self.eat(",")
+            {
+                return Ok(wires);
+            }
+        }
+    }
+
+    /// `net op net`, `~net` or `~(net op net)` on the right of an `assign`.
+    fn expression(&mut self, out: String) -> Result<Gate, ParseError> {
+        let inverted = self.eat("~");
+        let grouped = inverted && self.eat("(");
+        let in0 = self.net()?;
+
+        let (logic, in1) = match self.peek() {
+            Some(op @ ("&" | "|" | "^")) if !inverted || grouped => {
+                self.pos += 1;
+                let logic = match (op, inverted) {
+                    ("&", false) => Logic::And,
+                    ("|", false) => Logic::Or,
+                    ("^", false) => Logic::Xor,
+                    ("&", true) => Logic::Nand,
+                    ("|", true) => Logic::Nor,
+                    _ => Logic::Xnor,
+                };
+                (logic, self.net()?)
+            }
+            _ if inverted && !grouped => (Logic::Not, in0.clone()),
+            Some(token) => return Err(self.error(token, "`&`, `|` or `^`")),
+            None => return Err(ParseError::eof(self.input, "`&`, `|` or `^`")),
+        };
+        if grouped {
+            self.expect(")")?;
+        }
+
+        Ok(Gate {
+            in0,
+            in1,
+            logic,
+            out,
+        })
+    }
+
+    /// A primitive instance, e.g. `and g0 (out, in0, in1)`.
+    fn instance(&mut self, logic: Logic) -> Result<Gate, ParseError> {
+        if self.peek() != Some("(") {
+            self.identifier()?;
+        }
+        self.expect("(")?;
+        let out = self.net()?;
+        self.expect(",")?;
+        let in0 = self.net()?;
+        let in1 = match logic {
+            Logic::Not => in0.clone(),
+            _ => {
+                self.expect(",")?;
+                self.net()?
+            }
+        };
+        match self.next("`)`")? {
+            ")" => (),
+            "," => {
+                let supported = match logic {
+                    Logic::Not => "only one-input `not` gates are supported",
+                    _ => "only two-input gates are supported",
+                };
+                return Err(ParseError::at(
+                    self.input,
+                    self.tokens[self.pos - 1],
+                    supported,
+                ));
+            }
+            token => return Err(self.error(token, "`)`")),
+        }
+
+        Ok(Gate {
+            in0,
+            in1,
+            logic,
+            out,
+        })
+    }
+}
+
+fn bits(name: &str, range: &Option<Vec<usize>>) -> Vec<String> {
+    match range {
+        Some(bits) => bits.iter().map(|bit| format!("{name}{bit:02}")).collect(),
+        None => vec![name.to_string()],
+    }
+}
+
+/// Reads a single module of gate-level Verilog back into a device: ports
+/// and wires with optional `[msb:lsb]` ranges, primitive instances of
+/// `and`, `or`, `xor`, `not`, `nand`, `nor` and `xnor`, and `assign`s of one
+/// operator. Bit 3 of bus `x` becomes the wire `x03`, and every input starts
+/// at 0.
+pub fn from_verilog(input: &str) -> Result<Device, ParseError> {
+    let mut parser = Parser {
+        input,
+        tokens: tokenize(input),
+        pos: 0,
+    };
+    let mut wires = HashMap::new();
+    let mut gates = Vec::new();
+
+    parser.expect("module")?;
+    parser.identifier()?;
+    if parser.eat("(") && !parser.eat(")") {
+        loop {
+            match parser.peek() {
+                Some("input") => {
+                    parser.pos += 1;
+                    wires.extend(parser.declaration()?.into_iter().map(|wire| (wire, 0)));
+                }
+                Some("output") => {
+                    parser.pos += 1;
+                    parser.declaration()?;
+                }
+                // Declared in the body instead
+                _ => {
+                    parser.identifier()?;
+                }
+            }
+            if !parser.eat(",") {
+                break;
+            }
+        }
+        parser.expect(")")?;
+    }
+    parser.expect(";")?;
+
+    loop {
+        let token = parser.next("`endmodule`")?;
+        match token {
+            "endmodule" => break,
+            "input" | "output" | "wire" => {
+                let declared = parser.declaration()?;
+                if token == "input" {
+                    wires.extend(declared.into_iter().map(|wire| (wire, 0)));
+                }
+            }
+            "assign" => {
+                let out = parser.net()?;
+                parser.expect("=")?;
+                gates.push(parser.expression(out)?);
+            }
+            _ => match from_primitive(token) {
+                Some(logic) => gates.push(parser.instance(logic)?),
+                None => return Err(parser.error(token, "a declaration, `assign` or a gate")),
+            },
+        }
+        if token != "endmodule" {
+            parser.expect(";")?;
+        }
+    }
+
+    match parser.peek() {
+        Some(token) => Err(parser.error(token, "nothing after `endmodule`")),
+        None => Ok(Device { wires, gates }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{device, simulator::Simulator};
+
+    const ADDER: &str = "x00: 1
+    x01: 0
+    y00: 1
+    y01: 1
+
+    x00 XOR y00 -> z00
+    x00 AND y00 -> c00
+    x01 XOR y01 -> t01
+    t01 XOR c00 -> z01
+    x01 AND y01 -> and
+    t01 AND c00 -> b01
+    and OR b01 -> z02";
+
+    #[test]
+    fn it_writes_verilog() {
+        let device = device::parse(ADDER).unwrap();
+
+        assert_eq!(
+            to_verilog(&device, "adder"),
+            "module adder (
+    input wire [1:0] x,
+    input wire [1:0] y,
+    output wire [2:0] z
+);
+    wire \\and ;
+    wire b01;
+    wire c00;
+    wire t01;
+
+    xor g0 (z[0], x[0], y[0]);
+    and g1 (c00, x[0], y[0]);
+    xor g2 (t01, x[1], y[1]);
+    xor g3 (z[1], t01, c00);
+    and g4 (\\and , x[1], y[1]);
+    and g5 (b01, t01, c00);
+    or g6 (z[2], \\and , b01);
+endmodule"
+        );
+    }
+
+    #[test]
+    fn it_reads_back_what_it_writes() {
+        let device = device::parse(ADDER).unwrap();
+        let read = from_verilog(&to_verilog(&device, "adder")).unwrap();
+
+        assert_eq!(read.gates, device.gates);
+        assert_eq!(read.wires.len(), device.wires.len());
+        assert!(read
+            .wires
+            .keys()
+            .all(|wire| device.wires.contains_key(wire)));
+        assert_eq!(Simulator::from_device(&read).unwrap().eval(3, 1), 4);
+    }
+
+    #[test]
+    fn it_reads_other_forms() {
+        let device = from_verilog(
+            "// Ports declared in the body
+            module m (a, b, z);
+                input a, b;
+                output [1:0] z;
+                wire n;
+                /* one gate per form */
+                not (n, a);
+                assign z[0] = ~(n ^ b);
+                assign z[1] = ~n;
+                nand u1 (\\and , a, b);
+            endmodule",
+        )
+        .unwrap();
+
+        assert_eq!(
+            device.wires,
+            HashMap::from([("a".to_string(), 0), ("b".to_string(), 0)])
+        );
+        let gates = device
+            .gates
+            .iter()
+            .map(|gate| (gate.logic, gate.inputs(), gate.out.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            gates,
+            vec![
+                (Logic::Not, vec!["a"], "n"),
+                (Logic::Xnor, vec!["n", "b"], "z00"),
+                (Logic::Not, vec!["n"], "z01"),
+                (Logic::Nand, vec!["a", "b"], "and"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_rejects_unsupported_verilog() {
+        let error = |input| from_verilog(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("module m;\n  mux u0 (a, b, c);\nendmodule"),
+            "2:3: expected a declaration, `assign` or a gate, found `mux`"
+        );
+        assert_eq!(
+            error("module m;\n  and u0 (a, b, c, d);\nendmodule"),
+            "2:18: only two-input gates are supported"
+        );
+        assert_eq!(
+            error("module m;\n  assign a = b + c;\nendmodule"),
+            "2:16: expected `&`, `|` or `^`, found `+`"
+        );
+        assert_eq!(
+            error("module m;\n  wire a;"),
+            "2:10: expected `endmodule`, found end of input"
+        );
+    }
+}
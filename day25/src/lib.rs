@@ -0,0 +1,23 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+    type Input<'a> = part1::Schematics;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        part1::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        unreachable!("day 25 only has one part")
+    }
+}
@@ -0,0 +1,183 @@
+use common::{Answer, ParseError};
+use itertools::Itertools;
+
+/// The column heights of every lock and every key.
+pub type Schematics = (Vec<Vec<u8>>, Vec<Vec<u8>>);
+
+pub fn parse(input: &str) -> Result<Schematics, ParseError> {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+
+    for block in input.split("\n\n").filter(|block| !block.trim().is_empty()) {
+        let mut lock = false;
+        let mut heights: Vec<u8> = vec![0; 5];
+
+        for (n_line, line) in block.trim().lines().enumerate() {
+            let line = line.trim();
+            if let Some(ch) = line.chars().find(|&ch| ch != '#' && ch != '.') {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("unexpected `{ch}`, expected `#` or `.`"),
+                ));
+            }
+            if line.len() != 5 {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected 5 columns, found {}", line.len()),
+                ));
+            }
+
+            if n_line == 0 {
+                lock = line.chars().all(|c| c == '#');
+                continue;
+            }
+
+            if n_line == 6 {
+                break;
+            }
+
+            line.chars().enumerate().for_each(|(idx, ch)| {
+                if ch == '#' {
+                    heights[idx] += 1;
+                }
+            });
+        }
+
+        if lock {
+            locks.push(heights);
+        } else {
+            keys.push(heights);
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+fn count_fits(locks: &[Vec<u8>], keys: &[Vec<u8>]) -> u32 {
+    keys.iter()
+        .cartesian_product(locks.iter())
+        .filter(|(key, lock)| key.iter().zip(lock.iter()).all(|(a, b)| a + b <= 5))
+        .count()
+        .try_into()
+        .unwrap()
+}
+pub fn solve((locks, keys): &Schematics) -> Answer {
+    count_fits(locks, keys).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_the_input() {
+        let input = "#####
+        .####
+        .####
+        .####
+        .#.#.
+        .#...
+        .....
+
+        #####
+        ##.##
+        .#.##
+        ...##
+        ...#.
+        ...#.
+        .....
+
+        .....
+        #....
+        #....
+        #...#
+        #.#.#
+        #.###
+        #####
+
+        .....
+        .....
+        #.#..
+        ###..
+        ###.#
+        ###.#
+        #####
+
+        .....
+        .....
+        .....
+        #....
+        #.#..
+        #.#.#
+        #####"
+            .to_string();
+        let (locks, keys) = parse(&input).unwrap();
+
+        assert_eq!(locks.len(), 2);
+        assert_eq!(keys.len(), 3);
+        assert!(locks.contains(&vec![0, 5, 3, 4, 3]));
+        assert!(locks.contains(&vec![1, 2, 0, 5, 3]));
+        assert!(keys.contains(&vec![5, 0, 2, 1, 3]));
+        assert!(keys.contains(&vec![4, 3, 4, 0, 2]));
+        assert!(keys.contains(&vec![3, 0, 2, 0, 1]));
+    }
+
+    #[test]
+    fn it_solves() {
+        let input = "#####
+        .####
+        .####
+        .####
+        .#.#.
+        .#...
+        .....
+
+        #####
+        ##.##
+        .#.##
+        ...##
+        ...#.
+        ...#.
+        .....
+
+        .....
+        #....
+        #....
+        #...#
+        #.#.#
+        #.###
+        #####
+
+        .....
+        .....
+        #.#..
+        ###..
+        ###.#
+        ###.#
+        #####
+
+        .....
+        .....
+        .....
+        #....
+        #.#..
+        #.#.#
+        #####"
+            .to_string();
+        let (locks, keys) = parse(&input).unwrap();
+        let result = count_fits(&locks, &keys);
+
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn it_rejects_bad_schematics() {
+        let input = "#####\n.#..x";
+        assert_eq!(
+            parse(input).unwrap_err().to_string(),
+            "2:1: unexpected `x`, expected `#` or `.`"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Grid, Point};
+use std::fmt;
+
+/// The foreground colours a cell can be drawn in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    #[default]
+    Plain,
+    Grey,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    pub const ALL: [Colour; 8] = [
+        Colour::Plain,
+        Colour::Grey,
+        Colour::Red,
+        Colour::Green,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Magenta,
+        Colour::Cyan,
+    ];
+
+    /// The colour as red, green and blue, for drawing frames as images.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Colour::Plain => [204, 204, 204],
+            Colour::Grey => [48, 48, 48],
+            Colour::Red => [220, 50, 47],
+            Colour::Green => [80, 200, 80],
+            Colour::Yellow => [230, 190, 40],
+            Colour::Blue => [60, 110, 230],
+            Colour::Magenta => [200, 70, 200],
+            Colour::Cyan => [40, 190, 200],
+        }
+    }
+
+    /// The ANSI escape code that switches to this colour.
+    pub fn ansi(self) -> &'static str {
+        match self {
+            Colour::Plain => "\x1b[0m",
+            Colour::Grey => "\x1b[90m",
+            Colour::Red => "\x1b[31m",
+            Colour::Green => "\x1b[32m",
+            Colour::Yellow => "\x1b[33m",
+            Colour::Blue => "\x1b[34m",
+            Colour::Magenta => "\x1b[35m",
+            Colour::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+/// One character of a frame and its colour.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub const fn new(ch: char, colour: Colour) -> Self {
+        Cell { ch, colour }
+    }
+
+    pub const fn plain(ch: char) -> Self {
+        Cell::new(ch, Colour::Plain)
+    }
+}
+
+/// One step of a simulation, drawn as coloured characters with a caption
+/// underneath.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+/// The frames of a simulation, produced lazily as it runs.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Frame {
+            cells,
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Draws the frame for a terminal, only switching colour where it
+    /// changes and resetting it at the end of every row.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        let mut colour = Colour::Plain;
+
+        for (point, cell) in self.cells.iter() {
+            if point.x == 0 && point.y > 0 {
+                text.push_str(Colour::Plain.ansi());
+                text.push('\n');
+                colour = Colour::Plain;
+            }
+            if cell.colour != colour {
+                text.push_str(cell.colour.ansi());
+                colour = cell.colour;
+            }
+            text.push(cell.ch);
+        }
+
+        text.push_str(Colour::Plain.ansi());
+        if !self.caption.is_empty() {
+            text.push('\n');
+            text.push_str(&self.caption);
+        }
+        text
+    }
+}
+
+/// The frame without colours.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cells.render(|_, cell| cell.ch))?;
+        if !self.caption.is_empty() {
+            write!(f, "\n{}", self.caption)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draws every cell of the grid as a frame.
+    pub fn frame(&self, f: impl Fn(Point, &T) -> Cell) -> Frame {
+        let cells = self.iter().map(|(point, cell)| f(point, cell)).collect();
+
+        Frame::new(Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Frame {
+        Grid::from_fn(3, 2, |p| (p.x + p.y) % 2 == 0).frame(|_, &wall| {
+            if wall {
+                Cell::new('#', Colour::Red)
+            } else {
+                Cell::plain('.')
+            }
+        })
+    }
+
+    #[test]
+    fn it_draws_frames() {
+        let frame = checkerboard().with_caption("step 1");
+
+        assert_eq!(frame.to_string(), "#.#\n.#.\nstep 1");
+        assert_eq!(frame.cells[Point::new(1, 1)], Cell::new('#', Colour::Red));
+    }
+
+    #[test]
+    fn it_draws_colours_for_a_terminal() {
+        assert_eq!(
+            checkerboard().to_ansi(),
+            "\x1b[31m#\x1b[0m.\x1b[31m#\x1b[0m\n.\x1b[31m#\x1b[0m.\x1b[0m"
+        );
+    }
+}
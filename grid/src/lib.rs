@@ -0,0 +1,301 @@
+use common::ParseError;
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+mod direction;
+mod frame;
+mod point;
+
+pub use direction::Direction;
+pub use frame::{Cell, Colour, Frame, Frames};
+pub use point::Point;
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character. Blank lines around the grid and
+    /// indentation are ignored, every row must be the same width, and an
+    /// error from `f` is reported at the character it rejected.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(Point, char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let rows = input.trim().lines().map(str::trim).collect::<Vec<_>>();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        if width == 0 {
+            return Err(ParseError::eof(input, "a grid"));
+        }
+
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            let n_cols = row.chars().count();
+            if n_cols != width {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!("expected {width} columns, found {n_cols}"),
+                ));
+            }
+
+            for (x, (offset, ch)) in row.char_indices().enumerate() {
+                let cell = f(Point::new(x as i32, y as i32), ch)
+                    .map_err(|message| ParseError::at(input, &row[offset..], message))?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i32).contains(&point.x) && (0..self.height as i32).contains(&point.y)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    /// The cell at `point`, or `None` if it is off the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// Every point holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// The orthogonal neighbours of `point` that are on the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// The surrounding points of `point` that are on the grid, including
+    /// diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours8()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Sets every cell to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid back to text, one character per cell.
+    pub fn render(&self, f: impl Fn(Point, &T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for (point, cell) in self.iter() {
+            if point.x == 0 && point.y > 0 {
+                text.push('\n');
+            }
+            text.push(f(point, cell));
+        }
+
+        text
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of any characters.
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |_, ch| Ok(ch))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {width}x{height} grid"))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|_, &ch| ch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_and_renders() {
+        let input = "
+        #..
+        .S#
+        ";
+        let grid = Grid::parse_chars(input).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(1, 1)], 'S');
+        assert_eq!(grid.to_string(), "#..\n.S#");
+    }
+
+    #[test]
+    fn it_rejects_bad_grids() {
+        assert_eq!(
+            Grid::parse_chars("#..\n.#").unwrap_err().to_string(),
+            "2:1: expected 3 columns, found 2"
+        );
+        assert_eq!(
+            Grid::parse("..\n.x", |_, ch| match ch {
+                '.' => Ok(false),
+                _ => Err(format!("unexpected `{ch}`")),
+            })
+            .unwrap_err()
+            .to_string(),
+            "2:2: unexpected `x`"
+        );
+        assert!(Grid::parse_chars("\n").is_err());
+    }
+
+    #[test]
+    fn it_checks_bounds() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid[Point::new(2, 1)] = 7;
+
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&7));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.find(&7), Some(Point::new(2, 1)));
+        assert_eq!(grid.find(&8), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "3,0 is outside the 3x2 grid")]
+    fn it_panics_off_the_grid() {
+        let grid = Grid::new(3, 2, 0);
+        let _ = grid[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn it_finds_neighbours_on_the_grid() {
+        let grid = Grid::from_fn(3, 3, |p| p.x + p.y);
+
+        assert_eq!(grid.neighbours(Point::ORIGIN).count(), 2);
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.find_all(&2).collect::<Vec<_>>(),
+            vec![Point::new(2, 0), Point::new(1, 1), Point::new(0, 2)]
+        );
+    }
+
+    #[test]
+    fn it_renders_other_cells() {
+        let grid = Grid::from_fn(2, 2, |p| p.x == p.y);
+
+        assert_eq!(
+            grid.render(|_, &wall| if wall { '#' } else { '.' }),
+            "#.\n.#"
+        );
+    }
+}
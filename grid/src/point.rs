@@ -0,0 +1,142 @@
+use crate::Direction;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position on a grid. `x` is the column and `y` is the row, so `y` grows
+/// downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    /// The offsets of the eight surrounding points, clockwise from up.
+    pub const NEIGHBOURS8: [Point; 8] = [
+        Point::new(0, -1),
+        Point::new(1, -1),
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// The four orthogonal neighbours, in [`Direction::ALL`] order.
+    pub fn neighbours(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The eight surrounding points, clockwise from up.
+    pub fn neighbours8(self) -> [Point; 8] {
+        Point::NEIGHBOURS8.map(|offset| self + offset)
+    }
+
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, n: i32) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_does_arithmetic() {
+        let a = Point::new(2, 3);
+        let b = Point::new(-1, 4);
+
+        assert_eq!(a + b, Point::new(1, 7));
+        assert_eq!(a - b, Point::new(3, -1));
+        assert_eq!(a * 3, Point::new(6, 9));
+        assert_eq!(-a, Point::new(-2, -3));
+        assert_eq!(a.manhattan(b), 4);
+    }
+
+    #[test]
+    fn it_finds_neighbours() {
+        let p = Point::new(1, 1);
+
+        assert_eq!(
+            p.neighbours(),
+            [
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(1, 2),
+                Point::new(0, 1)
+            ]
+        );
+        assert_eq!(p.neighbours8().len(), 8);
+        assert!(!p.neighbours8().contains(&p));
+    }
+}
@@ -0,0 +1,448 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cost of a step, e.g. `u32` or `usize`. The default value is zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Follows `parents` back from `end` to the state with no parent, and
+/// returns the states from there to `end`.
+pub fn reconstruct_path<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, end: &S) -> Vec<S> {
+    let mut path = vec![end.clone()];
+
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+/// Breadth first search for the fewest steps from `start` to a state where
+/// `success` holds. Returns the states along the path, including both ends.
+pub fn bfs<S, I>(
+    start: &S,
+    mut successors: impl FnMut(&S) -> I,
+    mut success: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start.clone()]);
+
+    while let Some(state) = queue.pop_front() {
+        if success(&state) {
+            return Some(reconstruct_path(&parents, &state));
+        }
+
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Every state reached so far, by index, with its best known cost.
+struct Explored<S, C> {
+    states: Vec<S>,
+    costs: Vec<C>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Explored<S, C> {
+    fn new(start: &S) -> Self {
+        Explored {
+            states: vec![start.clone()],
+            costs: vec![C::default()],
+            index: HashMap::from([(start.clone(), 0)]),
+        }
+    }
+
+    /// Returns the index of `state`, adding it with `cost` if it is new.
+    fn insert(&mut self, state: S, cost: C) -> (usize, bool) {
+        if let Some(&i) = self.index.get(&state) {
+            return (i, false);
+        }
+
+        let i = self.states.len();
+        self.index.insert(state.clone(), i);
+        self.states.push(state);
+        self.costs.push(cost);
+        (i, true)
+    }
+
+    fn path(&self, parents: &[Option<usize>], end: usize) -> Vec<S> {
+        let mut path = vec![self.states[end].clone()];
+        let mut current = end;
+
+        while let Some(parent) = parents[current] {
+            path.push(self.states[parent].clone());
+            current = parent;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// A* search for the cheapest path from `start` to a state where `success`
+/// holds. `heuristic` must never overestimate the remaining cost. Returns the
+/// states along the path and its total cost.
+pub fn astar<S, C, I>(
+    start: &S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut success: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut explored = Explored::new(start);
+    let mut parents = vec![None];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // A cheaper way here has already been expanded.
+        if cost > explored.costs[i] {
+            continue;
+        }
+
+        let state = explored.states[i].clone();
+        if success(&state) {
+            return Some((explored.path(&parents, i), cost));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            let (j, is_new) = explored.insert(next, next_cost);
+
+            if is_new {
+                parents.push(Some(i));
+            } else if next_cost < explored.costs[j] {
+                explored.costs[j] = next_cost;
+                parents[j] = Some(i);
+            } else {
+                continue;
+            }
+
+            heap.push(Reverse((estimate, next_cost, j)));
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's search for the cheapest path from `start` to a state where
+/// `success` holds. Returns the states along the path and its total cost.
+pub fn dijkstra<S, C, I>(
+    start: &S,
+    successors: impl FnMut(&S) -> I,
+    success: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Dijkstra's search that keeps every cheapest path rather than one of them.
+/// Returns the paths to every end state reached at the lowest cost, and that
+/// cost.
+pub fn dijkstra_all<S, C, I>(
+    start: &S,
+    mut successors: impl FnMut(&S) -> I,
+    mut success: impl FnMut(&S) -> bool,
+) -> Option<(ShortestPaths<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut explored = Explored::new(start);
+    let mut predecessors = vec![Vec::new()];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+    let mut best = None;
+    let mut ends = Vec::new();
+
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > explored.costs[i] {
+            continue;
+        }
+        // Everything left is more expensive than the ends already found.
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        let state = explored.states[i].clone();
+        if success(&state) {
+            best = Some(cost);
+            ends.push(i);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let (j, is_new) = explored.insert(next, next_cost);
+
+            if is_new {
+                predecessors.push(vec![i]);
+            } else if next_cost < explored.costs[j] {
+                explored.costs[j] = next_cost;
+                predecessors[j] = vec![i];
+            } else {
+                if next_cost == explored.costs[j] && !predecessors[j].contains(&i) {
+                    predecessors[j].push(i);
+                }
+                continue;
+            }
+
+            heap.push(Reverse((next_cost, j)));
+        }
+    }
+
+    best.map(|cost| {
+        let paths = ShortestPaths {
+            states: explored.states,
+            predecessors,
+            ends,
+        };
+        (paths, cost)
+    })
+}
+
+/// Every cheapest path from a search, as the predecessors of each state on
+/// them. The start state has no predecessors.
+#[derive(Debug)]
+pub struct ShortestPaths<S> {
+    states: Vec<S>,
+    predecessors: Vec<Vec<usize>>,
+    ends: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// The end states reached at the lowest cost.
+    pub fn ends(&self) -> impl Iterator<Item = &S> {
+        self.ends.iter().map(|&i| &self.states[i])
+    }
+
+    /// Every state that lies on at least one of the paths.
+    pub fn on_any_path(&self) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.ends.clone();
+
+        while let Some(i) = stack.pop() {
+            if seen.insert(i) {
+                stack.extend(&self.predecessors[i]);
+            }
+        }
+
+        seen.into_iter().map(|i| self.states[i].clone()).collect()
+    }
+
+    /// The number of distinct paths, without listing them.
+    pub fn count(&self) -> usize {
+        let mut counts = HashMap::new();
+
+        self.ends
+            .iter()
+            .map(|&end| self.count_to(end, &mut counts))
+            .sum()
+    }
+
+    fn count_to(&self, i: usize, counts: &mut HashMap<usize, usize>) -> usize {
+        if self.predecessors[i].is_empty() {
+            return 1;
+        }
+        if let Some(&count) = counts.get(&i) {
+            return count;
+        }
+
+        let count = self.predecessors[i]
+            .iter()
+            .map(|&parent| self.count_to(parent, counts))
+            .sum();
+        counts.insert(i, count);
+        count
+    }
+
+    /// One of the paths, from the start to the first end found.
+    pub fn path(&self) -> Vec<S> {
+        let mut path = Vec::new();
+        let mut current = self.ends.first().copied();
+
+        while let Some(i) = current {
+            path.push(self.states[i].clone());
+            current = self.predecessors[i].first().copied();
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Every path, from the start to each end.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        let mut stack = self.ends.iter().map(|&end| vec![end]).collect::<Vec<_>>();
+
+        while let Some(partial) = stack.pop() {
+            let first = *partial.last().unwrap();
+
+            if self.predecessors[first].is_empty() {
+                let path = partial.iter().rev().map(|&i| self.states[i].clone());
+                paths.push(path.collect());
+                continue;
+            }
+
+            for &parent in self.predecessors[first].iter() {
+                let mut longer = partial.clone();
+                longer.push(parent);
+                stack.push(longer);
+            }
+        }
+
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::{Grid, Point};
+
+    fn maze(input: &str) -> (Grid<char>, Point, Point) {
+        let grid = Grid::parse_chars(input).unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        (grid, start, end)
+    }
+
+    fn open(grid: &Grid<char>, point: Point) -> Vec<Point> {
+        grid.neighbours(point)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn it_reconstructs_paths() {
+        let parents = HashMap::from([('c', 'b'), ('b', 'a')]);
+
+        assert_eq!(reconstruct_path(&parents, &'c'), vec!['a', 'b', 'c']);
+        assert_eq!(reconstruct_path(&parents, &'a'), vec!['a']);
+    }
+
+    #[test]
+    fn it_searches_breadth_first() {
+        let (grid, start, end) = maze(
+            "#####
+            #S..#
+            #.#.#
+            #..E#
+            #####",
+        );
+        let path = bfs(&start, |&p| open(&grid, p), |&p| p == end).unwrap();
+
+        assert_eq!(path.len(), 5);
+        assert_eq!(path[0], start);
+        assert_eq!(path[4], end);
+
+        let walled = bfs(&start, |&p| open(&grid, p), |&p| grid[p] == '#');
+        assert_eq!(walled, None);
+    }
+
+    #[test]
+    fn it_finds_the_cheapest_path() {
+        // The direct edge is more expensive than going the long way round.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+        ]);
+        let successors = |s: &char| edges.get(s).cloned().unwrap_or_default();
+
+        assert_eq!(
+            dijkstra(&'a', successors, |&s| s == 'd'),
+            Some((vec!['a', 'b', 'c', 'd'], 6))
+        );
+        assert_eq!(dijkstra(&'a', successors, |&s| s == 'z'), None);
+    }
+
+    #[test]
+    fn it_searches_with_a_heuristic() {
+        let (grid, start, end) = maze(
+            "#######
+            #S....#
+            #.###.#
+            #.....#
+            #....E#
+            #######",
+        );
+        let successors = |p: &Point| open(&grid, *p).into_iter().map(|next| (next, 1));
+        let mut expanded = 0;
+
+        let (path, cost) = astar(
+            &start,
+            successors,
+            |p| {
+                expanded += 1;
+                p.manhattan(end)
+            },
+            |&p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!(dijkstra(&start, successors, |&p| p == end).unwrap().1, cost);
+        assert!(expanded > 0);
+    }
+
+    #[test]
+    fn it_keeps_every_shortest_path() {
+        let (grid, start, end) = maze(
+            "#####
+            #S..#
+            #.#.#
+            #..E#
+            #####",
+        );
+        let successors = |p: &Point| open(&grid, *p).into_iter().map(|next| (next, 1));
+        let (paths, cost) = dijkstra_all(&start, successors, |&p| p == end).unwrap();
+
+        assert_eq!(cost, 4);
+        assert_eq!(paths.ends().collect::<Vec<_>>(), vec![&end]);
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.paths().len(), 2);
+        assert_eq!(paths.on_any_path().len(), 8);
+        assert_eq!(paths.path().len(), 5);
+        assert!(paths.paths().contains(&paths.path()));
+    }
+
+    #[test]
+    fn it_keeps_every_cheapest_end() {
+        let edges = HashMap::from([('a', vec![('x', 2), ('y', 2), ('z', 3)])]);
+        let successors = |s: &char| edges.get(s).cloned().unwrap_or_default();
+        let (paths, cost) = dijkstra_all(&'a', successors, |s| s != &'a').unwrap();
+
+        assert_eq!(cost, 2);
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.on_any_path(), HashSet::from(['a', 'x', 'y']));
+    }
+}